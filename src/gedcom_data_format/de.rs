@@ -4,6 +4,7 @@ use serde::{
   de::{self, DeserializeSeed, EnumAccess, MapAccess, SeqAccess, VariantAccess, Visitor},
   forward_to_deserialize_any, Deserialize,
 };
+use std::{borrow::Cow, str::FromStr};
 
 pub fn from_str<'a, T>(s: &'a str) -> Result<T>
 where
@@ -18,11 +19,23 @@ where
   }
 }
 
+/// The cross-reference ID of a record (_e.g._ `@I1@` in `0 @I1@ INDI`) is exposed as a map entry
+/// with this key, before any other field of the record.
+pub const XREF_ID_KEY: &str = "XREF_ID";
+
+/// A struct shared by lines with different tags (_e.g._ every event in `1 BIRT Y` and `1 DEAT Y`)
+/// cannot name a field after its own tag, so if it has a field with this key, the line value is
+/// exposed with this key instead.
+pub const LINE_VALUE_KEY: &str = "LINE_VALUE";
+
 #[derive(Debug, PartialEq)]
 enum DeserializerState {
-  DeserialisingKey,
-  DeserialisingValue,
-  DeserialisingStringValue,
+  Key,
+  Value,
+  StringValue,
+  XrefIdKey,
+  XrefIdValue,
+  LineValueKey,
 }
 use DeserializerState::*;
 
@@ -32,49 +45,183 @@ pub struct Deserializer<'de> {
   current_line: GedcomLine<'de>,
   next_line: Option<GedcomLine<'de>>,
   state: DeserializerState,
+  /// Lines already read as elements of an earlier sequence, which are skipped along with their
+  /// substructures. A line is identified by the length of the input from its start.
+  read_ahead_lines: Vec<usize>,
 }
 
 impl<'de> Deserializer<'de> {
+  #[allow(clippy::should_implement_trait)]
   pub fn from_str(input: &'de str) -> Result<Self> {
     let (remaining_input, current_line) = parse_gedcom_line(input)?;
-
-    if remaining_input.is_empty() {
-      return Ok(Deserializer {
-        remaining_input,
-        current_line,
-        next_line: None,
-        state: DeserialisingValue,
-      });
-    }
-
-    let (remaining_input, next_line) = parse_gedcom_line(remaining_input)?;
-    Ok(Deserializer {
+    let mut deserializer = Deserializer {
       remaining_input,
       current_line,
-      next_line: Some(next_line),
-      state: DeserialisingValue,
-    })
+      next_line: None,
+      state: Value,
+      read_ahead_lines: Vec::new(),
+    };
+    deserializer.next_line = deserializer.read_line()?;
+    Ok(deserializer)
   }
 
   fn parse_next_line(&mut self) -> Result<()> {
     // TODO Return an Error if next line is None;
-    let next_line = self.next_line.unwrap();
+    self.current_line = self.next_line.unwrap();
+    self.next_line = self.read_line()?;
+    Ok(())
+  }
 
-    if self.remaining_input.is_empty() {
-      self.current_line = next_line;
-      self.next_line = None;
-      return Ok(());
+  /// Reads the line that follows the next line, passing over the lines that were read ahead.
+  fn read_line(&mut self) -> Result<Option<GedcomLine<'de>>> {
+    while !self.remaining_input.is_empty() {
+      let position = self.remaining_input.len();
+      let (remaining_input, line) = parse_gedcom_line(self.remaining_input)?;
+      self.remaining_input = remaining_input;
+      match self
+        .read_ahead_lines
+        .iter()
+        .position(|&read| read == position)
+      {
+        Some(index) => {
+          self.read_ahead_lines.swap_remove(index);
+          self.skip_substructures(line.level)?;
+        }
+        None => return Ok(Some(line)),
+      }
     }
+    Ok(None)
+  }
 
-    let (remaining_input, next_next_line) = parse_gedcom_line(self.remaining_input)?;
-    self.current_line = next_line;
-    self.next_line = Some(next_next_line);
-    self.remaining_input = remaining_input;
+  fn skip_substructures(&mut self, level: u8) -> Result<()> {
+    while !self.remaining_input.is_empty() {
+      let (remaining_input, line) = parse_gedcom_line(self.remaining_input)?;
+      if line.level <= level {
+        break;
+      }
+      self.remaining_input = remaining_input;
+    }
     Ok(())
   }
+
+  /// Finds the lines with the given level and tag that come after the next line, up to the end of
+  /// the structure that holds them, and marks them to be skipped. A structure may repeat a tag
+  /// with other tags in between (_e.g._ `2 SOUR`, `2 NOTE` and then `2 SOUR` again), and these
+  /// lines are the rest of the sequence begun by the first of them.
+  fn read_ahead(&mut self, level: u8, tag: &str) -> Result<Vec<&'de str>> {
+    let mut lines = Vec::new();
+    if self
+      .next_line
+      .map(|line| line.level < level)
+      .unwrap_or(true)
+    {
+      return Ok(lines);
+    }
+    let mut remaining_input = self.remaining_input;
+    while !remaining_input.is_empty() {
+      let (rest, line) = parse_gedcom_line(remaining_input)?;
+      if line.level < level {
+        break;
+      }
+      if line.level == level && line.tag == tag {
+        self.read_ahead_lines.push(remaining_input.len());
+        lines.push(remaining_input);
+      }
+      remaining_input = rest;
+    }
+    Ok(lines)
+  }
+
+  fn is_continuation_of_current_line(&self, line: &GedcomLine) -> bool {
+    line.level == self.current_line.level + 1 && is_continuation_tag(line.tag)
+  }
+
+  fn has_line_value(&self) -> bool {
+    self.current_line.value.is_some()
+      || self
+        .next_line
+        .map(|line| self.is_continuation_of_current_line(&line))
+        .unwrap_or(false)
+  }
+
+  /// Whether the current line has sub-lines other than CONT and CONC continuation lines. This
+  /// needs to look past the next line, as a long value may be followed by its substructures.
+  fn has_substructures(&self) -> bool {
+    let mut remaining_input = self.remaining_input;
+    let mut next_line = self.next_line;
+    while let Some(line) = next_line {
+      if line.level != self.current_line.level + 1 {
+        return false;
+      }
+      if !is_continuation_tag(line.tag) {
+        return true;
+      }
+      next_line = match parse_gedcom_line(remaining_input) {
+        Ok((rest, line)) => {
+          remaining_input = rest;
+          Some(line)
+        }
+        Err(_) => None,
+      };
+    }
+    false
+  }
+
+  /// Reads the value of the current line, joining any CONT (with a newline) and CONC (without a
+  /// separator) lines that follow it. The value is only copied if there are continuation lines.
+  fn parse_line_value(&mut self) -> Result<Cow<'de, str>> {
+    let line_level = self.current_line.level;
+    let mut value = Cow::Borrowed(self.current_line.value.unwrap_or(""));
+    let mut has_value = self.current_line.value.is_some();
+
+    while let Some(line) = self.next_line {
+      if line.level != line_level + 1 || !is_continuation_tag(line.tag) {
+        break;
+      }
+      self.parse_next_line()?;
+      let continuation = value.to_mut();
+      if line.tag == "CONT" {
+        continuation.push('\n');
+      }
+      continuation.push_str(line.value.unwrap_or(""));
+      has_value = true;
+    }
+
+    if has_value {
+      Ok(value)
+    } else {
+      Err(Error::ExpectedGedcomLineWithValue)
+    }
+  }
+
+  fn parse_value<T: FromStr>(&self) -> Result<T> {
+    self
+      .current_line
+      .value
+      .ok_or(Error::ExpectedGedcomLineWithValue)?
+      .parse()
+      .map_err(|_| Error::ExpectedNumber)
+  }
+}
+
+fn is_continuation_tag(tag: &str) -> bool {
+  tag == "CONT" || tag == "CONC"
 }
 
-impl<'de, 'a> de::Deserializer<'de> for &'a mut Deserializer<'de> {
+macro_rules! deserialize_number {
+  ($($method:ident => $visit:ident,)*) => {
+    $(
+      fn $method<V>(self, visitor: V) -> Result<V::Value>
+      where
+        V: Visitor<'de>,
+      {
+        visitor.$visit(self.parse_value()?)
+      }
+    )*
+  };
+}
+
+impl<'de> de::Deserializer<'de> for &mut Deserializer<'de> {
   type Error = Error;
 
   fn deserialize_any<V>(self, visitor: V) -> Result<V::Value>
@@ -82,14 +229,9 @@ impl<'de, 'a> de::Deserializer<'de> for &'a mut Deserializer<'de> {
     V: Visitor<'de>,
   {
     match self.state {
-      DeserialisingKey => self.deserialize_str(visitor),
-      DeserialisingStringValue => self.deserialize_str(visitor),
-      DeserialisingValue => {
-        if self
-          .next_line
-          .map(|line| line.level == self.current_line.level + 1)
-          .unwrap_or_default()
-        {
+      Key | StringValue | XrefIdKey | XrefIdValue | LineValueKey => self.deserialize_str(visitor),
+      Value => {
+        if self.has_substructures() {
           self.deserialize_map(visitor)
         } else {
           self.deserialize_str(visitor)
@@ -103,27 +245,46 @@ impl<'de, 'a> de::Deserializer<'de> for &'a mut Deserializer<'de> {
     V: Visitor<'de>,
   {
     match self.state {
-      DeserialisingKey => visitor.visit_borrowed_str(self.current_line.tag),
-      _ => {
-        let value = self
+      Key => visitor.visit_borrowed_str(self.current_line.tag),
+      XrefIdKey => visitor.visit_borrowed_str(XREF_ID_KEY),
+      LineValueKey => visitor.visit_borrowed_str(LINE_VALUE_KEY),
+      XrefIdValue => {
+        let xref_id = self
           .current_line
-          .value
-          .ok_or(Error::ExpectedGedcomLineWithValue)?;
-        visitor.visit_borrowed_str(value)
+          .xref_id
+          .ok_or(Error::ExpectedGedcomLineWithXrefId)?;
+        visitor.visit_borrowed_str(xref_id)
+      }
+      _ => {
+        let tag = self.current_line.tag;
+        match self.parse_line_value()? {
+          Cow::Borrowed(value) => visitor.visit_borrowed_str(value),
+          // Joining continuation lines makes a new string, which a `&str` field cannot borrow.
+          Cow::Owned(value) => visitor.visit_string(value).map_err(|error: Error| {
+            Error::UnborrowableContinuedValue(tag.to_string(), error.to_string())
+          }),
+        }
       }
     }
   }
 
-  fn deserialize_seq<V>(mut self, visitor: V) -> Result<V::Value>
+  fn deserialize_string<V>(self, visitor: V) -> Result<V::Value>
+  where
+    V: Visitor<'de>,
+  {
+    self.deserialize_str(visitor)
+  }
+
+  fn deserialize_seq<V>(self, visitor: V) -> Result<V::Value>
   where
     V: Visitor<'de>,
   {
-    let value = visitor.visit_seq(GedcomSequenceAccess::new(&mut self))?;
+    let value = visitor.visit_seq(GedcomSequenceAccess::new(self))?;
 
     Ok(value)
   }
 
-  fn deserialize_map<V>(mut self, visitor: V) -> Result<V::Value>
+  fn deserialize_map<V>(self, visitor: V) -> Result<V::Value>
   where
     V: Visitor<'de>,
   {
@@ -136,7 +297,7 @@ impl<'de, 'a> de::Deserializer<'de> for &'a mut Deserializer<'de> {
     }
 
     let map_level = self.current_line.level + 1;
-    let value = visitor.visit_map(GedcomMapAccess::new(&mut self, map_level))?;
+    let value = visitor.visit_map(GedcomMapAccess::new(self, map_level, false))?;
 
     Ok(value)
   }
@@ -178,26 +339,79 @@ impl<'de, 'a> de::Deserializer<'de> for &'a mut Deserializer<'de> {
     }
   }
 
+  fn deserialize_struct<V>(
+    self,
+    _name: &'static str,
+    fields: &'static [&'static str],
+    visitor: V,
+  ) -> Result<V::Value>
+  where
+    V: Visitor<'de>,
+  {
+    // Unlike a map, a struct may be given by a single line (_e.g._ `1 FAMS @F1@`), in which case
+    // only its implicit field is present.
+    let map_level = self.current_line.level + 1;
+    let has_line_value_field = fields.contains(&LINE_VALUE_KEY);
+    visitor.visit_map(GedcomMapAccess::new(self, map_level, has_line_value_field))
+  }
+
+  /// Skips the current line and all of its substructures, whether or not it has a value. An
+  /// ignored implicit field only skips the line value, as its substructures are the parent's fields.
+  fn deserialize_ignored_any<V>(self, visitor: V) -> Result<V::Value>
+  where
+    V: Visitor<'de>,
+  {
+    if self.state != Value {
+      return self.deserialize_any(visitor);
+    }
+    let level = self.current_line.level;
+    while self
+      .next_line
+      .map(|line| line.level > level)
+      .unwrap_or(false)
+    {
+      self.parse_next_line()?;
+    }
+    visitor.visit_unit()
+  }
+
+  deserialize_number! {
+    deserialize_i8 => visit_i8,
+    deserialize_i16 => visit_i16,
+    deserialize_i32 => visit_i32,
+    deserialize_i64 => visit_i64,
+    deserialize_u8 => visit_u8,
+    deserialize_u16 => visit_u16,
+    deserialize_u32 => visit_u32,
+    deserialize_u64 => visit_u64,
+  }
+
   forward_to_deserialize_any! {
-      bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char string
+      bool i128 u128 f32 f64 char
       bytes byte_buf unit unit_struct newtype_struct tuple
-      tuple_struct struct identifier ignored_any
+      tuple_struct identifier
   }
 }
 
 struct GedcomSequenceAccess<'a, 'de: 'a> {
   de: &'a mut Deserializer<'de>,
   first: bool,
+  level: u8,
   tag: &'a str,
+  /// The elements that are not next to the first ones, in reverse order, once they are found.
+  later_elements: Option<Vec<&'de str>>,
 }
 
 impl<'a, 'de> GedcomSequenceAccess<'a, 'de> {
   fn new(de: &'a mut Deserializer<'de>) -> Self {
+    let level = de.current_line.level;
     let tag = de.current_line.tag;
     Self {
       de,
       first: true,
+      level,
       tag,
+      later_elements: None,
     }
   }
 }
@@ -216,15 +430,29 @@ impl<'de, 'a> SeqAccess<'de> for GedcomSequenceAccess<'a, 'de> {
     if self
       .de
       .next_line
-      .map(|line| line.level != 0 && line.tag != self.tag)
-      .unwrap_or(true)
+      .map(|line| line.level == self.level && (line.level == 0 || line.tag == self.tag))
+      .unwrap_or(false)
     {
-      return Ok(None);
+      self.de.parse_next_line()?;
+      self.de.state = Value;
+      return seed.deserialize(&mut *self.de).map(Some);
     }
 
-    self.de.parse_next_line()?;
-    self.de.state = DeserialisingValue;
-    seed.deserialize(&mut *self.de).map(Some)
+    // Every tag is an element of the sequence of records.
+    if self.level == 0 {
+      return Ok(None);
+    }
+    if self.later_elements.is_none() {
+      let mut later_elements = self.de.read_ahead(self.level, self.tag)?;
+      later_elements.reverse();
+      self.later_elements = Some(later_elements);
+    }
+    match self.later_elements.as_mut().and_then(Vec::pop) {
+      Some(input) => seed
+        .deserialize(&mut Deserializer::from_str(input)?)
+        .map(Some),
+      None => Ok(None),
+    }
   }
 }
 
@@ -232,16 +460,21 @@ impl<'de, 'a> SeqAccess<'de> for GedcomSequenceAccess<'a, 'de> {
 struct GedcomMapAccess<'a, 'de: 'a> {
   de: &'a mut Deserializer<'de>,
   first: bool,
+  seeding_xref_id: bool,
   seeding_implicit_field: bool,
+  has_line_value_field: bool,
   map_level: u8,
 }
 
 impl<'a, 'de> GedcomMapAccess<'a, 'de> {
-  fn new(de: &'a mut Deserializer<'de>, map_level: u8) -> Self {
+  fn new(de: &'a mut Deserializer<'de>, map_level: u8, has_line_value_field: bool) -> Self {
+    let seeding_xref_id = de.current_line.xref_id.is_some();
     Self {
-      de: de,
+      de,
       first: true,
+      seeding_xref_id,
       seeding_implicit_field: false,
+      has_line_value_field,
       map_level,
     }
   }
@@ -254,11 +487,20 @@ impl<'de, 'a> MapAccess<'de> for GedcomMapAccess<'a, 'de> {
   where
     K: DeserializeSeed<'de>,
   {
+    if self.seeding_xref_id {
+      self.de.state = XrefIdKey;
+      return seed.deserialize(&mut *self.de).map(Some);
+    }
+
     if self.first {
       self.first = false;
-      if self.de.current_line.value.is_some() {
+      if self.de.has_line_value() {
         self.seeding_implicit_field = true;
-        self.de.state = DeserialisingKey;
+        self.de.state = if self.has_line_value_field {
+          LineValueKey
+        } else {
+          Key
+        };
         return seed.deserialize(&mut *self.de).map(Some);
       }
     }
@@ -273,7 +515,7 @@ impl<'de, 'a> MapAccess<'de> for GedcomMapAccess<'a, 'de> {
     }
 
     self.de.parse_next_line()?;
-    self.de.state = DeserialisingKey;
+    self.de.state = Key;
     seed.deserialize(&mut *self.de).map(Some)
   }
 
@@ -281,11 +523,14 @@ impl<'de, 'a> MapAccess<'de> for GedcomMapAccess<'a, 'de> {
   where
     V: DeserializeSeed<'de>,
   {
-    if self.seeding_implicit_field {
-      self.de.state = DeserialisingStringValue;
+    if self.seeding_xref_id {
+      self.de.state = XrefIdValue;
+      self.seeding_xref_id = false;
+    } else if self.seeding_implicit_field {
+      self.de.state = StringValue;
       self.seeding_implicit_field = false;
     } else {
-      self.de.state = DeserialisingValue;
+      self.de.state = Value;
     }
     seed.deserialize(&mut *self.de)
   }
@@ -363,9 +608,9 @@ impl<'de, 'a> EnumAccess<'de> for GedcomComplexEnumAccess<'a, 'de> {
   where
     V: DeserializeSeed<'de>,
   {
-    self.de.state = DeserialisingKey;
+    self.de.state = Key;
     let variant = seed.deserialize(&mut *self.de)?;
-    self.de.state = DeserialisingValue;
+    self.de.state = Value;
     Ok((variant, self))
   }
 }
@@ -638,6 +883,40 @@ fn test_struct_with_array_field_and_then_another_value() {
   );
 }
 
+#[test]
+fn test_struct_with_array_field_split_by_another_value() {
+  use serde::Deserialize;
+
+  #[derive(Deserialize, PartialEq, Debug)]
+  struct Foo<'a> {
+    #[serde(borrow, rename(deserialize = "BAR"))]
+    bar: Vec<Bar<'a>>,
+    #[serde(rename(deserialize = "BAZ"))]
+    baz: &'a str,
+  }
+
+  #[derive(Deserialize, PartialEq, Debug)]
+  struct Bar<'a> {
+    #[serde(borrow, rename(deserialize = "QUX"), default)]
+    qux: Vec<&'a str>,
+  }
+
+  let input = "0 FOO\n1 BAR\n2 QUX qux1\n2 QUUX\n2 QUX qux2\n1 BAZ baz\n1 BAR\n2 QUX qux3\n";
+  let result: Foo = from_str(input).expect("No errors during this test");
+  assert_eq!(
+    result,
+    Foo {
+      bar: vec![
+        Bar {
+          qux: vec!["qux1", "qux2"]
+        },
+        Bar { qux: vec!["qux3"] }
+      ],
+      baz: "baz"
+    }
+  );
+}
+
 #[test]
 fn test_struct_with_array_field_but_only_one_value() {
   use serde::Deserialize;
@@ -745,3 +1024,293 @@ fn test_enum_value() {
   let result: Foo = from_str(input).expect("No errors during this test");
   assert_eq!(result, Foo { bar: Qux });
 }
+
+#[test]
+fn test_struct_with_xref_id() {
+  use serde::Deserialize;
+
+  #[derive(Deserialize, PartialEq, Debug)]
+  struct Foo<'a> {
+    #[serde(rename(deserialize = "XREF_ID"))]
+    xref_id: &'a str,
+    #[serde(rename(deserialize = "BAR"))]
+    bar: &'a str,
+  }
+
+  let input = "0 @F1@ FOO\n1 BAR bar\n";
+  let result: Foo = from_str(input).expect("No errors during this test");
+  assert_eq!(
+    result,
+    Foo {
+      xref_id: "@F1@",
+      bar: "bar"
+    }
+  );
+}
+
+#[test]
+fn test_sequence_of_enum_with_xref_ids() {
+  use serde::Deserialize;
+
+  #[derive(Deserialize, PartialEq, Debug)]
+  struct Foo<'a> {
+    #[serde(rename(deserialize = "XREF_ID"))]
+    xref_id: &'a str,
+    #[serde(rename(deserialize = "FOO"))]
+    foo: &'a str,
+    #[serde(rename(deserialize = "BAR"))]
+    bar: &'a str,
+  }
+
+  #[derive(Deserialize, PartialEq, Debug)]
+  enum FooBaz<'a> {
+    #[serde(borrow, rename = "FOO")]
+    Foo(Foo<'a>),
+    #[serde(rename = "BAZ")]
+    Baz,
+  }
+
+  let input = "0 @F1@ FOO foo1\n1 BAR bar1\n0 @F2@ FOO foo2\n1 BAR bar2\n0 BAZ\n";
+  let result: Vec<FooBaz> = from_str(input).expect("No errors during this test");
+  assert_eq!(
+    result,
+    vec![
+      FooBaz::Foo(Foo {
+        xref_id: "@F1@",
+        foo: "foo1",
+        bar: "bar1"
+      }),
+      FooBaz::Foo(Foo {
+        xref_id: "@F2@",
+        foo: "foo2",
+        bar: "bar2"
+      }),
+      FooBaz::Baz
+    ]
+  );
+}
+
+#[test]
+fn test_struct_with_number_field() {
+  use serde::Deserialize;
+
+  #[derive(Deserialize, PartialEq, Debug)]
+  struct Foo {
+    #[serde(rename(deserialize = "BAR"))]
+    bar: u8,
+  }
+
+  let input = "0 FOO\n1 BAR 12\n";
+  let result: Foo = from_str(input).expect("No errors during this test");
+  assert_eq!(result, Foo { bar: 12 });
+}
+
+#[test]
+fn test_sequence_of_single_line_structs() {
+  use serde::Deserialize;
+
+  #[derive(Deserialize, PartialEq, Debug)]
+  struct Foo<'a> {
+    #[serde(borrow, rename(deserialize = "BAR"))]
+    bar: Vec<Bar<'a>>,
+  }
+
+  #[derive(Deserialize, PartialEq, Debug)]
+  struct Bar<'a> {
+    #[serde(rename(deserialize = "BAR"))]
+    bar: &'a str,
+    #[serde(rename(deserialize = "BAZ"))]
+    baz: Option<&'a str>,
+  }
+
+  let input = "0 FOO\n1 BAR bar1\n1 BAR bar2\n2 BAZ baz\n1 BAR bar3\n";
+  let result: Foo = from_str(input).expect("No errors during this test");
+  assert_eq!(
+    result,
+    Foo {
+      bar: vec![
+        Bar {
+          bar: "bar1",
+          baz: None
+        },
+        Bar {
+          bar: "bar2",
+          baz: Some("baz")
+        },
+        Bar {
+          bar: "bar3",
+          baz: None
+        }
+      ]
+    }
+  );
+}
+
+#[test]
+fn test_struct_with_array_field_and_then_another_record() {
+  use serde::Deserialize;
+
+  #[derive(Deserialize, PartialEq, Debug)]
+  struct Foo<'a> {
+    #[serde(borrow, rename(deserialize = "BAR"))]
+    bar: Vec<&'a str>,
+  }
+
+  #[derive(Deserialize, PartialEq, Debug)]
+  enum FooBaz<'a> {
+    #[serde(borrow, rename = "FOO")]
+    Foo(Foo<'a>),
+    #[serde(rename = "BAZ")]
+    Baz,
+  }
+
+  let input = "0 FOO\n1 BAR bar1\n1 BAR bar2\n0 BAZ\n";
+  let result: Vec<FooBaz> = from_str(input).expect("No errors during this test");
+  assert_eq!(
+    result,
+    vec![
+      FooBaz::Foo(Foo {
+        bar: vec!["bar1", "bar2"]
+      }),
+      FooBaz::Baz
+    ]
+  );
+}
+
+#[test]
+fn test_struct_with_continued_field() {
+  use serde::Deserialize;
+  use std::borrow::Cow;
+
+  #[derive(Deserialize, PartialEq, Debug)]
+  struct Foo<'a> {
+    #[serde(borrow, rename(deserialize = "BAR"))]
+    bar: Cow<'a, str>,
+    #[serde(borrow, rename(deserialize = "BAZ"))]
+    baz: Cow<'a, str>,
+    #[serde(rename(deserialize = "QUX"))]
+    qux: &'a str,
+  }
+
+  let input =
+    "0 FOO\n1 BAR bar\n2 CONT second line\n2 CONT\n2 CONC continued\n1 BAZ baz\n1 QUX qux\n";
+  let result: Foo = from_str(input).expect("No errors during this test");
+  assert_eq!(
+    result,
+    Foo {
+      bar: Cow::Owned("bar\nsecond line\ncontinued".to_string()),
+      baz: Cow::Borrowed("baz"),
+      qux: "qux"
+    }
+  );
+  assert!(matches!(result.baz, Cow::Borrowed(_)));
+}
+
+#[test]
+fn test_struct_with_continued_implicit_field() {
+  use serde::Deserialize;
+  use std::borrow::Cow;
+
+  #[derive(Deserialize, PartialEq, Debug)]
+  struct Foo<'a> {
+    #[serde(borrow, rename(deserialize = "FOO"))]
+    foo: Cow<'a, str>,
+    #[serde(rename(deserialize = "BAR"))]
+    bar: &'a str,
+  }
+
+  let input = "0 FOO Lorem ips\n1 CONC um dolor\n1 CONT sit amet\n1 BAR bar\n";
+  let result: Foo = from_str(input).expect("No errors during this test");
+  assert_eq!(
+    result,
+    Foo {
+      foo: Cow::Borrowed("Lorem ipsum dolor\nsit amet"),
+      bar: "bar"
+    }
+  );
+}
+
+#[test]
+fn test_continued_value_in_borrowed_field() {
+  use serde::Deserialize;
+
+  #[derive(Deserialize, PartialEq, Debug)]
+  struct Foo<'a> {
+    #[serde(rename(deserialize = "BAR"))]
+    bar: &'a str,
+  }
+
+  let input = "0 FOO\n1 BAR bar\n2 CONC bar\n";
+  let result: Result<Foo> = from_str(input);
+  assert!(matches!(
+    result,
+    Err(Error::UnborrowableContinuedValue(tag, _)) if tag == "BAR"
+  ));
+}
+
+#[test]
+fn test_struct_with_ignored_continued_field() {
+  use serde::Deserialize;
+
+  #[derive(Deserialize, PartialEq, Debug)]
+  struct Foo<'a> {
+    #[serde(rename(deserialize = "BAR"))]
+    bar: &'a str,
+  }
+
+  let input = "0 FOO foo\n1 CONT foo\n1 BAZ baz\n2 CONC baz\n1 BAR bar\n";
+  let result: Foo = from_str(input).expect("No errors during this test");
+  assert_eq!(result, Foo { bar: "bar" });
+}
+
+#[test]
+fn test_ignored_field_without_value() {
+  use serde::Deserialize;
+
+  #[derive(Deserialize, PartialEq, Debug)]
+  struct Foo<'a> {
+    #[serde(rename(deserialize = "BAR"))]
+    bar: &'a str,
+  }
+
+  let input = "0 FOO\n1 BAZ\n2 QUX\n1 BAR bar\n";
+  let result: Foo = from_str(input).expect("No errors during this test");
+  assert_eq!(result, Foo { bar: "bar" });
+}
+
+#[test]
+fn test_struct_with_line_value_field() {
+  use serde::Deserialize;
+
+  #[derive(Deserialize, PartialEq, Debug)]
+  struct Event<'a> {
+    #[serde(rename(deserialize = "LINE_VALUE"))]
+    value: Option<&'a str>,
+    #[serde(rename(deserialize = "DATE"))]
+    date: Option<&'a str>,
+  }
+
+  #[derive(Deserialize, PartialEq, Debug)]
+  struct Foo<'a> {
+    #[serde(borrow, rename(deserialize = "BIRT"))]
+    birth: Event<'a>,
+    #[serde(borrow, rename(deserialize = "DEAT"))]
+    death: Event<'a>,
+  }
+
+  let input = "0 FOO\n1 BIRT\n2 DATE 1900\n1 DEAT Y\n";
+  let result: Foo = from_str(input).expect("No errors during this test");
+  assert_eq!(
+    result,
+    Foo {
+      birth: Event {
+        value: None,
+        date: Some("1900")
+      },
+      death: Event {
+        value: Some("Y"),
+        date: None
+      }
+    }
+  );
+}
@@ -8,9 +8,17 @@ pub enum Error {
   Message(String),
   InvalidGedcomLine,
   ExpectedGedcomLineWithValue,
+  ExpectedGedcomLineWithXrefId,
+  ExpectedNumber,
   ExpectedMap,
   ExpectedMapEnd,
   TrailingCharacters,
+  ExpectedTag,
+  ExpectedImplicitFieldBeforeSubstructures,
+  /// The tag of the line, and the error from the field.
+  UnborrowableContinuedValue(String, String),
+  UnsupportedType,
+  Io(String),
 }
 
 impl ser::Error for Error {
@@ -31,11 +39,26 @@ impl Display for Error {
       Error::Message(msg) => formatter.write_str(msg),
       Error::InvalidGedcomLine => formatter.write_str("Invalid Gedcom line"),
       Error::ExpectedGedcomLineWithValue => formatter.write_str("Expected Gedcom Line with value"),
+      Error::ExpectedGedcomLineWithXrefId => {
+        formatter.write_str("Expected Gedcom Line with cross-reference ID")
+      }
+      Error::ExpectedNumber => formatter.write_str("Expected number"),
       Error::ExpectedMap => formatter.write_str("Expected map"),
       Error::ExpectedMapEnd => {
         formatter.write_str("Expected map to end (next level should be current level - 1)")
       }
       Error::TrailingCharacters => formatter.write_str("Trailing characters were left"),
+      Error::ExpectedTag => formatter.write_str("Expected a tag for this value"),
+      Error::ExpectedImplicitFieldBeforeSubstructures => {
+        formatter.write_str("Expected line value and cross-reference ID before any substructures")
+      }
+      Error::UnborrowableContinuedValue(tag, msg) => write!(
+        formatter,
+        "The value of {} is continued with CONT or CONC lines, so it must be read into a Cow<str> or String field: {}",
+        tag, msg
+      ),
+      Error::UnsupportedType => formatter.write_str("Type cannot be represented in Gedcom"),
+      Error::Io(msg) => formatter.write_str(msg),
     }
   }
 }
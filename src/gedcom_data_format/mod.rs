@@ -1,5 +1,7 @@
 mod de;
 mod error;
+mod ser;
 
 pub use de::{from_str, Deserializer};
 pub use error::{Error, Result};
+pub use ser::{to_string, to_writer, Serializer, Terminator, MAX_LINE_LENGTH};
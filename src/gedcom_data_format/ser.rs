@@ -0,0 +1,1032 @@
+use super::{
+  de::{LINE_VALUE_KEY, XREF_ID_KEY},
+  error::{Error, Result},
+};
+use serde::{
+  ser::{self, Impossible},
+  Serialize,
+};
+use std::{borrow::Cow, io::Write};
+
+/// The maximum length of a Gedcom line, including its level, XREF ID, tag, value, delimiters and
+/// terminator. Longer values are split over CONC lines.
+pub const MAX_LINE_LENGTH: usize = 255;
+
+pub fn to_string<T>(value: &T) -> Result<String>
+where
+  T: ?Sized + Serialize,
+{
+  let mut output = Vec::new();
+  to_writer(&mut output, value)?;
+  String::from_utf8(output).map_err(|error| Error::Message(error.to_string()))
+}
+
+pub fn to_writer<W, T>(writer: W, value: &T) -> Result<()>
+where
+  W: Write,
+  T: ?Sized + Serialize,
+{
+  let mut serializer = Serializer::new(writer);
+  value.serialize(&mut serializer)?;
+  serializer.flush_pending_line()
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Terminator {
+  #[default]
+  Lf,
+  CrLf,
+  Cr,
+}
+
+impl Terminator {
+  fn as_str(self) -> &'static str {
+    match self {
+      Terminator::Lf => "\n",
+      Terminator::CrLf => "\r\n",
+      Terminator::Cr => "\r",
+    }
+  }
+}
+
+#[derive(Debug)]
+struct PendingLine {
+  level: u8,
+  xref_id: Option<String>,
+  tag: Cow<'static, str>,
+  value: Option<String>,
+}
+
+/// Serializes values as Gedcom lines. Struct and map fields become lines one level deeper than
+/// their parent, and sequences repeat the tag of the field they are in. As in the `Deserializer`,
+/// a field named after its parent's tag (or the `LINE_VALUE` field) is the parent's line value, and
+/// the `XREF_ID` field is its cross-reference ID.
+#[derive(Debug)]
+pub struct Serializer<W> {
+  writer: W,
+  terminator: Terminator,
+  max_line_length: usize,
+  level: u8,
+  tag: Option<Cow<'static, str>>,
+  // A line is only written once all of its XREF ID and value are known, which is when its first
+  // substructure is written or when it ends.
+  pending_line: Option<PendingLine>,
+}
+
+impl<W: Write> Serializer<W> {
+  pub fn new(writer: W) -> Self {
+    Serializer {
+      writer,
+      terminator: Terminator::default(),
+      max_line_length: MAX_LINE_LENGTH,
+      level: 0,
+      tag: None,
+      pending_line: None,
+    }
+  }
+
+  pub fn with_terminator(mut self, terminator: Terminator) -> Self {
+    self.terminator = terminator;
+    self
+  }
+
+  pub fn with_max_line_length(mut self, max_line_length: usize) -> Self {
+    self.max_line_length = max_line_length;
+    self
+  }
+
+  pub fn into_inner(self) -> W {
+    self.writer
+  }
+
+  fn expect_tag(&self) -> Result<Cow<'static, str>> {
+    self.tag.clone().ok_or(Error::ExpectedTag)
+  }
+
+  fn start_line(&mut self, tag: Cow<'static, str>) -> Result<()> {
+    self.flush_pending_line()?;
+    self.pending_line = Some(PendingLine {
+      level: self.level,
+      xref_id: None,
+      tag,
+      value: None,
+    });
+    Ok(())
+  }
+
+  fn flush_pending_line(&mut self) -> Result<()> {
+    match self.pending_line.take() {
+      Some(line) => self.write_line(
+        line.level,
+        line.xref_id.as_deref(),
+        &line.tag,
+        line.value.as_deref(),
+      ),
+      None => Ok(()),
+    }
+  }
+
+  fn write_value(&mut self, value: Option<&str>) -> Result<()> {
+    let tag = self.expect_tag()?;
+    self.flush_pending_line()?;
+    self.write_line(self.level, None, &tag, value)
+  }
+
+  /// Writes a logical line, splitting its value over CONT lines at newlines and over CONC lines
+  /// where it would exceed the maximum line length.
+  fn write_line(
+    &mut self,
+    level: u8,
+    xref_id: Option<&str>,
+    tag: &str,
+    value: Option<&str>,
+  ) -> Result<()> {
+    let value = match value {
+      Some(value) => value,
+      None => return self.write_physical_line(level, xref_id, tag, ""),
+    };
+
+    let mut segments = value.split('\n');
+    let first_segment = segments.next().unwrap_or_default();
+    self.write_concatenated_line(level, xref_id, tag, first_segment, level + 1)?;
+    for segment in segments {
+      self.write_concatenated_line(level + 1, None, "CONT", segment, level + 1)?;
+    }
+    Ok(())
+  }
+
+  fn write_concatenated_line(
+    &mut self,
+    level: u8,
+    xref_id: Option<&str>,
+    tag: &str,
+    value: &str,
+    concatenation_level: u8,
+  ) -> Result<()> {
+    let available_length = self.available_length(level, xref_id, tag);
+    let (head, mut rest) = split_value(value, available_length);
+    self.write_physical_line(level, xref_id, tag, head)?;
+
+    let available_length = self.available_length(concatenation_level, None, "CONC");
+    while !rest.is_empty() {
+      let (head, tail) = split_value(rest, available_length);
+      self.write_physical_line(concatenation_level, None, "CONC", head)?;
+      rest = tail;
+    }
+    Ok(())
+  }
+
+  fn available_length(&self, level: u8, xref_id: Option<&str>, tag: &str) -> usize {
+    let prefix_length = level.to_string().len()
+      + xref_id
+        .map(|xref_id| xref_id.chars().count() + 1)
+        .unwrap_or(0)
+      + 1
+      + tag.chars().count()
+      + 1;
+    self
+      .max_line_length
+      .saturating_sub(prefix_length + self.terminator.as_str().len())
+  }
+
+  fn write_physical_line(
+    &mut self,
+    level: u8,
+    xref_id: Option<&str>,
+    tag: &str,
+    value: &str,
+  ) -> Result<()> {
+    let mut line = level.to_string();
+    if let Some(xref_id) = xref_id {
+      line.push(' ');
+      line.push_str(xref_id);
+    }
+    line.push(' ');
+    line.push_str(tag);
+    if !value.is_empty() {
+      line.push(' ');
+      line.push_str(value);
+    }
+    line.push_str(self.terminator.as_str());
+    self
+      .writer
+      .write_all(line.as_bytes())
+      .map_err(|error| Error::Io(error.to_string()))
+  }
+}
+
+/// Splits a value after at most `available_length` characters. The Gedcom spec does not allow
+/// splitting next to a space, as some systems trim trailing spaces, so the split is moved back to
+/// the nearest pair of non-space characters if possible.
+fn split_value(value: &str, available_length: usize) -> (&str, &str) {
+  let available_length = available_length.max(1);
+  let split_index = match value.char_indices().nth(available_length) {
+    Some((index, _)) => index,
+    None => return (value, ""),
+  };
+
+  let is_space_at = |index: usize| value[index..].starts_with(' ');
+  let is_space_before = |index: usize| value[..index].ends_with(' ');
+  let adjusted_split_index = value[..split_index]
+    .char_indices()
+    .map(|(index, _)| index)
+    .chain(std::iter::once(split_index))
+    .rev()
+    .find(|&index| index > 0 && !is_space_at(index) && !is_space_before(index))
+    .unwrap_or(split_index);
+
+  value.split_at(adjusted_split_index)
+}
+
+impl<'a, W: Write> ser::Serializer for &'a mut Serializer<W> {
+  type Ok = ();
+  type Error = Error;
+
+  type SerializeSeq = GedcomSequenceSerializer<'a, W>;
+  type SerializeTuple = GedcomSequenceSerializer<'a, W>;
+  type SerializeTupleStruct = GedcomSequenceSerializer<'a, W>;
+  type SerializeTupleVariant = Impossible<(), Error>;
+  type SerializeMap = GedcomMapSerializer<'a, W>;
+  type SerializeStruct = GedcomMapSerializer<'a, W>;
+  type SerializeStructVariant = GedcomMapSerializer<'a, W>;
+
+  fn serialize_bool(self, value: bool) -> Result<()> {
+    self.write_value(Some(if value { "Y" } else { "N" }))
+  }
+
+  fn serialize_i8(self, value: i8) -> Result<()> {
+    self.write_value(Some(&value.to_string()))
+  }
+
+  fn serialize_i16(self, value: i16) -> Result<()> {
+    self.write_value(Some(&value.to_string()))
+  }
+
+  fn serialize_i32(self, value: i32) -> Result<()> {
+    self.write_value(Some(&value.to_string()))
+  }
+
+  fn serialize_i64(self, value: i64) -> Result<()> {
+    self.write_value(Some(&value.to_string()))
+  }
+
+  fn serialize_u8(self, value: u8) -> Result<()> {
+    self.write_value(Some(&value.to_string()))
+  }
+
+  fn serialize_u16(self, value: u16) -> Result<()> {
+    self.write_value(Some(&value.to_string()))
+  }
+
+  fn serialize_u32(self, value: u32) -> Result<()> {
+    self.write_value(Some(&value.to_string()))
+  }
+
+  fn serialize_u64(self, value: u64) -> Result<()> {
+    self.write_value(Some(&value.to_string()))
+  }
+
+  fn serialize_f32(self, value: f32) -> Result<()> {
+    self.write_value(Some(&value.to_string()))
+  }
+
+  fn serialize_f64(self, value: f64) -> Result<()> {
+    self.write_value(Some(&value.to_string()))
+  }
+
+  fn serialize_char(self, value: char) -> Result<()> {
+    self.write_value(Some(&value.to_string()))
+  }
+
+  fn serialize_str(self, value: &str) -> Result<()> {
+    self.write_value(Some(value))
+  }
+
+  fn serialize_bytes(self, _value: &[u8]) -> Result<()> {
+    Err(Error::UnsupportedType)
+  }
+
+  fn serialize_none(self) -> Result<()> {
+    Ok(())
+  }
+
+  fn serialize_some<T>(self, value: &T) -> Result<()>
+  where
+    T: ?Sized + Serialize,
+  {
+    value.serialize(self)
+  }
+
+  fn serialize_unit(self) -> Result<()> {
+    self.write_value(None)
+  }
+
+  fn serialize_unit_struct(self, _name: &'static str) -> Result<()> {
+    self.serialize_unit()
+  }
+
+  fn serialize_unit_variant(
+    self,
+    _name: &'static str,
+    _variant_index: u32,
+    variant: &'static str,
+  ) -> Result<()> {
+    // Inside a field the variant is the line value (_e.g._ `1 CHAR UTF-8`), otherwise it is the
+    // tag (_e.g._ `0 TRLR`).
+    if self.tag.is_some() {
+      self.write_value(Some(variant))
+    } else {
+      self.tag = Some(Cow::Borrowed(variant));
+      self.write_value(None)
+    }
+  }
+
+  fn serialize_newtype_struct<T>(self, _name: &'static str, value: &T) -> Result<()>
+  where
+    T: ?Sized + Serialize,
+  {
+    value.serialize(self)
+  }
+
+  fn serialize_newtype_variant<T>(
+    self,
+    _name: &'static str,
+    _variant_index: u32,
+    variant: &'static str,
+    value: &T,
+  ) -> Result<()>
+  where
+    T: ?Sized + Serialize,
+  {
+    self.tag = Some(Cow::Borrowed(variant));
+    value.serialize(self)
+  }
+
+  fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq> {
+    Ok(GedcomSequenceSerializer::new(self))
+  }
+
+  fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple> {
+    self.serialize_seq(Some(len))
+  }
+
+  fn serialize_tuple_struct(
+    self,
+    _name: &'static str,
+    len: usize,
+  ) -> Result<Self::SerializeTupleStruct> {
+    self.serialize_seq(Some(len))
+  }
+
+  fn serialize_tuple_variant(
+    self,
+    _name: &'static str,
+    _variant_index: u32,
+    _variant: &'static str,
+    _len: usize,
+  ) -> Result<Self::SerializeTupleVariant> {
+    Err(Error::UnsupportedType)
+  }
+
+  fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap> {
+    GedcomMapSerializer::new(self)
+  }
+
+  fn serialize_struct(self, _name: &'static str, len: usize) -> Result<Self::SerializeStruct> {
+    self.serialize_map(Some(len))
+  }
+
+  fn serialize_struct_variant(
+    self,
+    _name: &'static str,
+    _variant_index: u32,
+    variant: &'static str,
+    len: usize,
+  ) -> Result<Self::SerializeStructVariant> {
+    self.tag = Some(Cow::Borrowed(variant));
+    self.serialize_map(Some(len))
+  }
+}
+
+pub struct GedcomSequenceSerializer<'a, W> {
+  ser: &'a mut Serializer<W>,
+  level: u8,
+  tag: Option<Cow<'static, str>>,
+}
+
+impl<'a, W: Write> GedcomSequenceSerializer<'a, W> {
+  fn new(ser: &'a mut Serializer<W>) -> Self {
+    let level = ser.level;
+    let tag = ser.tag.clone();
+    Self { ser, level, tag }
+  }
+
+  fn serialize_next<T>(&mut self, value: &T) -> Result<()>
+  where
+    T: ?Sized + Serialize,
+  {
+    self.ser.level = self.level;
+    self.ser.tag = self.tag.clone();
+    value.serialize(&mut *self.ser)
+  }
+}
+
+impl<'a, W: Write> ser::SerializeSeq for GedcomSequenceSerializer<'a, W> {
+  type Ok = ();
+  type Error = Error;
+
+  fn serialize_element<T>(&mut self, value: &T) -> Result<()>
+  where
+    T: ?Sized + Serialize,
+  {
+    self.serialize_next(value)
+  }
+
+  fn end(self) -> Result<()> {
+    Ok(())
+  }
+}
+
+impl<'a, W: Write> ser::SerializeTuple for GedcomSequenceSerializer<'a, W> {
+  type Ok = ();
+  type Error = Error;
+
+  fn serialize_element<T>(&mut self, value: &T) -> Result<()>
+  where
+    T: ?Sized + Serialize,
+  {
+    self.serialize_next(value)
+  }
+
+  fn end(self) -> Result<()> {
+    Ok(())
+  }
+}
+
+impl<'a, W: Write> ser::SerializeTupleStruct for GedcomSequenceSerializer<'a, W> {
+  type Ok = ();
+  type Error = Error;
+
+  fn serialize_field<T>(&mut self, value: &T) -> Result<()>
+  where
+    T: ?Sized + Serialize,
+  {
+    self.serialize_next(value)
+  }
+
+  fn end(self) -> Result<()> {
+    Ok(())
+  }
+}
+
+pub struct GedcomMapSerializer<'a, W> {
+  ser: &'a mut Serializer<W>,
+  level: u8,
+  tag: Cow<'static, str>,
+  key: Option<Cow<'static, str>>,
+}
+
+impl<'a, W: Write> GedcomMapSerializer<'a, W> {
+  fn new(ser: &'a mut Serializer<W>) -> Result<Self> {
+    let level = ser.level;
+    let tag = ser.expect_tag()?;
+    ser.start_line(tag.clone())?;
+    Ok(Self {
+      ser,
+      level,
+      tag,
+      key: None,
+    })
+  }
+
+  fn serialize_entry_value<T>(&mut self, key: Cow<'static, str>, value: &T) -> Result<()>
+  where
+    T: ?Sized + Serialize,
+  {
+    if key == XREF_ID_KEY || key == LINE_VALUE_KEY || key == self.tag {
+      let value = value.serialize(LineValueSerializer)?;
+      let line = self
+        .ser
+        .pending_line
+        .as_mut()
+        .ok_or(Error::ExpectedImplicitFieldBeforeSubstructures)?;
+      if key == XREF_ID_KEY {
+        line.xref_id = value;
+      } else {
+        line.value = value;
+      }
+      return Ok(());
+    }
+
+    self.ser.level = self.level + 1;
+    self.ser.tag = Some(key);
+    value.serialize(&mut *self.ser)
+  }
+}
+
+impl<'a, W: Write> ser::SerializeMap for GedcomMapSerializer<'a, W> {
+  type Ok = ();
+  type Error = Error;
+
+  fn serialize_key<T>(&mut self, key: &T) -> Result<()>
+  where
+    T: ?Sized + Serialize,
+  {
+    let key = key
+      .serialize(LineValueSerializer)?
+      .ok_or(Error::ExpectedTag)?;
+    self.key = Some(Cow::Owned(key));
+    Ok(())
+  }
+
+  fn serialize_value<T>(&mut self, value: &T) -> Result<()>
+  where
+    T: ?Sized + Serialize,
+  {
+    let key = self.key.take().ok_or(Error::ExpectedTag)?;
+    self.serialize_entry_value(key, value)
+  }
+
+  fn end(self) -> Result<()> {
+    self.ser.flush_pending_line()
+  }
+}
+
+impl<'a, W: Write> ser::SerializeStruct for GedcomMapSerializer<'a, W> {
+  type Ok = ();
+  type Error = Error;
+
+  fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<()>
+  where
+    T: ?Sized + Serialize,
+  {
+    self.serialize_entry_value(Cow::Borrowed(key), value)
+  }
+
+  fn end(self) -> Result<()> {
+    self.ser.flush_pending_line()
+  }
+}
+
+impl<'a, W: Write> ser::SerializeStructVariant for GedcomMapSerializer<'a, W> {
+  type Ok = ();
+  type Error = Error;
+
+  fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<()>
+  where
+    T: ?Sized + Serialize,
+  {
+    self.serialize_entry_value(Cow::Borrowed(key), value)
+  }
+
+  fn end(self) -> Result<()> {
+    self.ser.flush_pending_line()
+  }
+}
+
+/// Serializes the implicit field or XREF ID of a line, or a map key, which can only be scalars.
+struct LineValueSerializer;
+
+impl ser::Serializer for LineValueSerializer {
+  type Ok = Option<String>;
+  type Error = Error;
+
+  type SerializeSeq = Impossible<Option<String>, Error>;
+  type SerializeTuple = Impossible<Option<String>, Error>;
+  type SerializeTupleStruct = Impossible<Option<String>, Error>;
+  type SerializeTupleVariant = Impossible<Option<String>, Error>;
+  type SerializeMap = Impossible<Option<String>, Error>;
+  type SerializeStruct = Impossible<Option<String>, Error>;
+  type SerializeStructVariant = Impossible<Option<String>, Error>;
+
+  fn serialize_bool(self, value: bool) -> Result<Option<String>> {
+    self.serialize_str(if value { "Y" } else { "N" })
+  }
+
+  fn serialize_i8(self, value: i8) -> Result<Option<String>> {
+    Ok(Some(value.to_string()))
+  }
+
+  fn serialize_i16(self, value: i16) -> Result<Option<String>> {
+    Ok(Some(value.to_string()))
+  }
+
+  fn serialize_i32(self, value: i32) -> Result<Option<String>> {
+    Ok(Some(value.to_string()))
+  }
+
+  fn serialize_i64(self, value: i64) -> Result<Option<String>> {
+    Ok(Some(value.to_string()))
+  }
+
+  fn serialize_u8(self, value: u8) -> Result<Option<String>> {
+    Ok(Some(value.to_string()))
+  }
+
+  fn serialize_u16(self, value: u16) -> Result<Option<String>> {
+    Ok(Some(value.to_string()))
+  }
+
+  fn serialize_u32(self, value: u32) -> Result<Option<String>> {
+    Ok(Some(value.to_string()))
+  }
+
+  fn serialize_u64(self, value: u64) -> Result<Option<String>> {
+    Ok(Some(value.to_string()))
+  }
+
+  fn serialize_f32(self, value: f32) -> Result<Option<String>> {
+    Ok(Some(value.to_string()))
+  }
+
+  fn serialize_f64(self, value: f64) -> Result<Option<String>> {
+    Ok(Some(value.to_string()))
+  }
+
+  fn serialize_char(self, value: char) -> Result<Option<String>> {
+    Ok(Some(value.to_string()))
+  }
+
+  fn serialize_str(self, value: &str) -> Result<Option<String>> {
+    Ok(Some(value.to_string()))
+  }
+
+  fn serialize_bytes(self, _value: &[u8]) -> Result<Option<String>> {
+    Err(Error::UnsupportedType)
+  }
+
+  fn serialize_none(self) -> Result<Option<String>> {
+    Ok(None)
+  }
+
+  fn serialize_some<T>(self, value: &T) -> Result<Option<String>>
+  where
+    T: ?Sized + Serialize,
+  {
+    value.serialize(self)
+  }
+
+  fn serialize_unit(self) -> Result<Option<String>> {
+    Ok(None)
+  }
+
+  fn serialize_unit_struct(self, _name: &'static str) -> Result<Option<String>> {
+    Ok(None)
+  }
+
+  fn serialize_unit_variant(
+    self,
+    _name: &'static str,
+    _variant_index: u32,
+    variant: &'static str,
+  ) -> Result<Option<String>> {
+    self.serialize_str(variant)
+  }
+
+  fn serialize_newtype_struct<T>(self, _name: &'static str, value: &T) -> Result<Option<String>>
+  where
+    T: ?Sized + Serialize,
+  {
+    value.serialize(self)
+  }
+
+  fn serialize_newtype_variant<T>(
+    self,
+    _name: &'static str,
+    _variant_index: u32,
+    _variant: &'static str,
+    _value: &T,
+  ) -> Result<Option<String>>
+  where
+    T: ?Sized + Serialize,
+  {
+    Err(Error::UnsupportedType)
+  }
+
+  fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq> {
+    Err(Error::UnsupportedType)
+  }
+
+  fn serialize_tuple(self, _len: usize) -> Result<Self::SerializeTuple> {
+    Err(Error::UnsupportedType)
+  }
+
+  fn serialize_tuple_struct(
+    self,
+    _name: &'static str,
+    _len: usize,
+  ) -> Result<Self::SerializeTupleStruct> {
+    Err(Error::UnsupportedType)
+  }
+
+  fn serialize_tuple_variant(
+    self,
+    _name: &'static str,
+    _variant_index: u32,
+    _variant: &'static str,
+    _len: usize,
+  ) -> Result<Self::SerializeTupleVariant> {
+    Err(Error::UnsupportedType)
+  }
+
+  fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap> {
+    Err(Error::UnsupportedType)
+  }
+
+  fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<Self::SerializeStruct> {
+    Err(Error::UnsupportedType)
+  }
+
+  fn serialize_struct_variant(
+    self,
+    _name: &'static str,
+    _variant_index: u32,
+    _variant: &'static str,
+    _len: usize,
+  ) -> Result<Self::SerializeStructVariant> {
+    Err(Error::UnsupportedType)
+  }
+}
+
+#[test]
+fn test_simple_struct() {
+  #[derive(Serialize)]
+  enum Record<'a> {
+    #[serde(rename = "FOO")]
+    Foo(Foo<'a>),
+  }
+
+  #[derive(Serialize)]
+  struct Foo<'a> {
+    #[serde(rename = "BAR")]
+    bar: &'a str,
+  }
+
+  let result = to_string(&Record::Foo(Foo { bar: "bar" })).expect("No errors during this test");
+  assert_eq!(result, "0 FOO\n1 BAR bar\n");
+}
+
+#[test]
+fn test_struct_with_optional_field() {
+  #[derive(Serialize)]
+  enum Record<'a> {
+    #[serde(rename = "FOO")]
+    Foo(Foo<'a>),
+  }
+
+  #[derive(Serialize)]
+  struct Foo<'a> {
+    #[serde(rename = "BAR")]
+    bar: Option<&'a str>,
+    #[serde(rename = "BAZ")]
+    baz: Option<&'a str>,
+  }
+
+  let result = to_string(&Record::Foo(Foo {
+    bar: Some("bar"),
+    baz: None,
+  }))
+  .expect("No errors during this test");
+  assert_eq!(result, "0 FOO\n1 BAR bar\n");
+}
+
+#[test]
+fn test_struct_with_xref_id_and_implicit_field() {
+  #[derive(Serialize)]
+  enum Record<'a> {
+    #[serde(rename = "FOO")]
+    Foo(Foo<'a>),
+  }
+
+  #[derive(Serialize)]
+  struct Foo<'a> {
+    #[serde(rename = "XREF_ID")]
+    xref_id: &'a str,
+    #[serde(rename = "FOO")]
+    foo: &'a str,
+    #[serde(rename = "BAR")]
+    bar: &'a str,
+  }
+
+  let result = to_string(&Record::Foo(Foo {
+    xref_id: "@F1@",
+    foo: "foo",
+    bar: "bar",
+  }))
+  .expect("No errors during this test");
+  assert_eq!(result, "0 @F1@ FOO foo\n1 BAR bar\n");
+}
+
+#[test]
+fn test_nested_struct_and_array_field() {
+  #[derive(Serialize)]
+  enum Record<'a> {
+    #[serde(rename = "FOO")]
+    Foo(Foo<'a>),
+    #[serde(rename = "TRLR")]
+    Trailer,
+  }
+
+  #[derive(Serialize)]
+  struct Foo<'a> {
+    #[serde(rename = "BAR")]
+    bar: Bar<'a>,
+    #[serde(rename = "QUX")]
+    qux: Vec<&'a str>,
+  }
+
+  #[derive(Serialize)]
+  struct Bar<'a> {
+    #[serde(rename = "BAR")]
+    bar: &'a str,
+    #[serde(rename = "BAZ")]
+    baz: u8,
+  }
+
+  let result = to_string(&vec![
+    Record::Foo(Foo {
+      bar: Bar {
+        bar: "bar",
+        baz: 12,
+      },
+      qux: vec!["qux1", "qux2"],
+    }),
+    Record::Trailer,
+  ])
+  .expect("No errors during this test");
+  assert_eq!(
+    result,
+    "0 FOO\n1 BAR bar\n2 BAZ 12\n1 QUX qux1\n1 QUX qux2\n0 TRLR\n"
+  );
+}
+
+#[test]
+fn test_enum_value() {
+  #[derive(Serialize)]
+  enum Record {
+    #[serde(rename = "FOO")]
+    Foo(Foo),
+  }
+
+  #[derive(Serialize)]
+  struct Foo {
+    #[serde(rename = "BAR")]
+    bar: Bar,
+  }
+
+  #[derive(Serialize)]
+  enum Bar {
+    #[serde(rename = "qux")]
+    Qux,
+  }
+
+  let result = to_string(&Record::Foo(Foo { bar: Bar::Qux })).expect("No errors during this test");
+  assert_eq!(result, "0 FOO\n1 BAR qux\n");
+}
+
+#[test]
+fn test_struct_without_tag() {
+  #[derive(Serialize)]
+  struct Foo<'a> {
+    #[serde(rename = "BAR")]
+    bar: &'a str,
+  }
+
+  let result = to_string(&Foo { bar: "bar" });
+  assert_eq!(result, Err(Error::ExpectedTag));
+}
+
+#[test]
+fn test_multi_line_value() {
+  #[derive(Serialize)]
+  enum Record<'a> {
+    #[serde(rename = "NOTE")]
+    Note(Note<'a>),
+  }
+
+  #[derive(Serialize)]
+  struct Note<'a> {
+    #[serde(rename = "NOTE")]
+    text: &'a str,
+  }
+
+  let result = to_string(&Record::Note(Note {
+    text: "First line\n\nThird line",
+  }))
+  .expect("No errors during this test");
+  assert_eq!(result, "0 NOTE First line\n1 CONT\n1 CONT Third line\n");
+}
+
+#[test]
+fn test_long_value_is_split_with_conc() {
+  #[derive(Serialize)]
+  enum Record<'a> {
+    #[serde(rename = "NOTE")]
+    Note(Note<'a>),
+  }
+
+  #[derive(Serialize)]
+  struct Note<'a> {
+    #[serde(rename = "NOTE")]
+    text: &'a str,
+  }
+
+  // "0 NOTE " and the terminator leave 12 characters for the value on the first line, and
+  // "1 CONC " leaves 12 on the next.
+  let mut serializer = Serializer::new(Vec::new()).with_max_line_length(20);
+  Record::Note(Note {
+    text: "Lorem ipsum dolor sit amet\nconsectetur adipiscing",
+  })
+  .serialize(&mut serializer)
+  .expect("No errors during this test");
+  let result = String::from_utf8(serializer.into_inner()).unwrap();
+
+  // Splits are moved away from spaces.
+  assert_eq!(
+    result,
+    "0 NOTE Lorem ipsu\n1 CONC m dolor si\n1 CONC t amet\n1 CONT consectetu\n1 CONC r adipiscing\n"
+  );
+  assert!(result.lines().all(|line| line.len() < 20));
+}
+
+#[test]
+fn test_terminator() {
+  #[derive(Serialize)]
+  enum Record<'a> {
+    #[serde(rename = "FOO")]
+    Foo(Foo<'a>),
+  }
+
+  #[derive(Serialize)]
+  struct Foo<'a> {
+    #[serde(rename = "BAR")]
+    bar: &'a str,
+  }
+
+  let mut serializer = Serializer::new(Vec::new()).with_terminator(Terminator::CrLf);
+  Record::Foo(Foo { bar: "bar" })
+    .serialize(&mut serializer)
+    .expect("No errors during this test");
+  let result = String::from_utf8(serializer.into_inner()).unwrap();
+  assert_eq!(result, "0 FOO\r\n1 BAR bar\r\n");
+}
+
+#[test]
+fn test_line_value_field() {
+  #[derive(Serialize)]
+  struct Event<'a> {
+    #[serde(rename = "LINE_VALUE")]
+    value: Option<&'a str>,
+    #[serde(rename = "DATE")]
+    date: Option<&'a str>,
+  }
+
+  #[derive(Serialize)]
+  enum Record<'a> {
+    #[serde(rename = "DEAT")]
+    Death(Event<'a>),
+  }
+
+  let record = Record::Death(Event {
+    value: Some("Y"),
+    date: Some("1900"),
+  });
+  let gedcom = to_string(&record).expect("No errors during this test");
+  assert_eq!(gedcom, "0 DEAT Y\n1 DATE 1900\n");
+}
+
+#[test]
+fn test_round_trip() {
+  use super::from_str;
+  use serde::Deserialize;
+
+  #[derive(Deserialize, Serialize, PartialEq, Debug)]
+  enum Record<'a> {
+    #[serde(borrow, rename = "FOO")]
+    Foo(Foo<'a>),
+    #[serde(rename = "TRLR")]
+    Trailer,
+  }
+
+  #[derive(Deserialize, Serialize, PartialEq, Debug)]
+  struct Foo<'a> {
+    #[serde(rename = "XREF_ID")]
+    xref_id: &'a str,
+    #[serde(borrow, rename = "FOO")]
+    foo: Cow<'a, str>,
+    #[serde(rename = "BAR")]
+    bar: Vec<u16>,
+  }
+
+  let records = vec![
+    Record::Foo(Foo {
+      xref_id: "@F1@",
+      foo: "A very long value ".repeat(20).into(),
+      bar: vec![1, 2],
+    }),
+    Record::Trailer,
+  ];
+  let gedcom = to_string(&records).expect("No errors during this test");
+  let result: Vec<Record> = from_str(&gedcom).expect("No errors during this test");
+  assert_eq!(result, records);
+}
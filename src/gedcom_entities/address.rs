@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+/// A postal address. The phone numbers, email addresses, fax numbers and web pages that go with it
+/// are lines beside `ADDR`, so they are fields of the structure that has the address.
+#[derive(Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Address<'input> {
+  /// The whole address as it would be written on an envelope, one line per line.
+  #[serde(borrow, rename = "ADDR", default)]
+  pub address_line: Cow<'input, str>,
+  #[serde(borrow, rename = "ADR1")]
+  pub line1: Option<Cow<'input, str>>,
+  #[serde(borrow, rename = "ADR2")]
+  pub line2: Option<Cow<'input, str>>,
+  #[serde(borrow, rename = "ADR3")]
+  pub line3: Option<Cow<'input, str>>,
+  #[serde(borrow, rename = "CITY")]
+  pub city: Option<Cow<'input, str>>,
+  #[serde(borrow, rename = "STAE")]
+  pub state: Option<Cow<'input, str>>,
+  #[serde(borrow, rename = "POST")]
+  pub post_code: Option<Cow<'input, str>>,
+  #[serde(borrow, rename = "CTRY")]
+  pub country: Option<Cow<'input, str>>,
+}
@@ -0,0 +1,216 @@
+//! Comparison of fuzzy dates, such as `ABT 1900` or `BET 1880 AND 1885`, by the range of days
+//! that they could fall on.
+
+use super::{Date, DateApproximated, DatePeriod, DateRange, DateValue};
+use std::cmp::Ordering;
+
+/// The first and last days that a date could fall on, as Julian Day Numbers. A missing bound is
+/// open-ended, as in `BEF 1900`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DateBounds {
+  pub earliest: Option<i32>,
+  pub latest: Option<i32>,
+}
+
+/// How two dates are ordered, when they might fall on the same day.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DateComparison {
+  Before,
+  After,
+  Overlapping,
+}
+
+impl DateBounds {
+  pub fn day(julian_day_number: i32) -> DateBounds {
+    DateBounds::between(julian_day_number, julian_day_number)
+  }
+
+  pub fn between(earliest: i32, latest: i32) -> DateBounds {
+    DateBounds {
+      earliest: Some(earliest),
+      latest: Some(latest),
+    }
+  }
+
+  pub fn unbounded() -> DateBounds {
+    DateBounds {
+      earliest: None,
+      latest: None,
+    }
+  }
+
+  /// Dates are only before or after each other if none of their days overlap.
+  pub fn compare(&self, other: &DateBounds) -> DateComparison {
+    match (self.latest, other.earliest, self.earliest, other.latest) {
+      (Some(latest), Some(other_earliest), _, _) if latest < other_earliest => {
+        DateComparison::Before
+      }
+      (_, _, Some(earliest), Some(other_latest)) if earliest > other_latest => {
+        DateComparison::After
+      }
+      _ => DateComparison::Overlapping,
+    }
+  }
+
+  /// A total order for sorting, by earliest and then latest day, with open-ended bounds first and
+  /// last respectively.
+  pub fn cmp_chronologically(&self, other: &DateBounds) -> Ordering {
+    let earliest = |bounds: &DateBounds| bounds.earliest.unwrap_or(i32::MIN);
+    let latest = |bounds: &DateBounds| bounds.latest.unwrap_or(i32::MAX);
+    earliest(self)
+      .cmp(&earliest(other))
+      .then_with(|| latest(self).cmp(&latest(other)))
+  }
+}
+
+impl Date {
+  pub fn compare(&self, other: &Date) -> DateComparison {
+    self.bounds().compare(&other.bounds())
+  }
+}
+
+impl DateValue {
+  /// Approximated and interpreted dates have the bounds of their date, and phrases are unbounded.
+  pub fn bounds(&self) -> DateBounds {
+    match self {
+      DateValue::Date(date)
+      | DateValue::Approximated(DateApproximated::About(date))
+      | DateValue::Approximated(DateApproximated::Calculated(date))
+      | DateValue::Approximated(DateApproximated::Estimated(date))
+      | DateValue::Interpreted(date, _) => date.bounds(),
+      DateValue::Range(DateRange::Before(date)) => DateBounds {
+        earliest: None,
+        latest: date.bounds().earliest.map(|earliest| earliest - 1),
+      },
+      DateValue::Range(DateRange::After(date)) => DateBounds {
+        earliest: date.bounds().latest.map(|latest| latest + 1),
+        latest: None,
+      },
+      DateValue::Period(DatePeriod::From(date)) => DateBounds {
+        earliest: date.bounds().earliest,
+        latest: None,
+      },
+      DateValue::Period(DatePeriod::To(date)) => DateBounds {
+        earliest: None,
+        latest: date.bounds().latest,
+      },
+      DateValue::Range(DateRange::Between(start, end))
+      | DateValue::Period(DatePeriod::FromTo(start, end)) => DateBounds {
+        earliest: start.bounds().earliest,
+        latest: end.bounds().latest,
+      },
+      DateValue::Phrase(_) | DateValue::Unparsed(_) => DateBounds::unbounded(),
+    }
+  }
+
+  pub fn compare(&self, other: &DateValue) -> DateComparison {
+    self.bounds().compare(&other.bounds())
+  }
+
+  pub fn cmp_chronologically(&self, other: &DateValue) -> Ordering {
+    self.bounds().cmp_chronologically(&other.bounds())
+  }
+}
+
+#[test]
+fn compare_partial_dates() {
+  use crate::parsers::parse_date_value;
+
+  // Arrange
+  let parse = |input| parse_date_value(input).unwrap().1;
+  let june_1890 = parse("JUN 1890");
+  let in_1890 = parse("1890");
+  let in_1891 = parse("ABT 1891");
+
+  // Act
+  let comparisons = (
+    june_1890.compare(&in_1890),
+    in_1890.compare(&in_1891),
+    in_1891.compare(&june_1890),
+  );
+
+  // Assert
+  assert_eq!(
+    comparisons,
+    (
+      DateComparison::Overlapping,
+      DateComparison::Before,
+      DateComparison::After
+    )
+  );
+}
+
+#[test]
+fn compare_ranges_and_periods() {
+  use crate::parsers::parse_date_value;
+
+  // Arrange
+  let parse = |input| parse_date_value(input).unwrap().1;
+  let before_1900 = parse("BEF 1900");
+  let first_day_of_1900 = parse("1 JAN 1900");
+  let between = parse("BET 1880 AND 1885");
+  let from_1885 = parse("FROM 1885");
+
+  // Act
+  let comparisons = (
+    before_1900.compare(&first_day_of_1900),
+    between.compare(&from_1885),
+    from_1885.compare(&before_1900),
+  );
+
+  // Assert
+  assert_eq!(
+    comparisons,
+    (
+      DateComparison::Before,
+      DateComparison::Overlapping,
+      DateComparison::Overlapping
+    )
+  );
+}
+
+#[test]
+fn compare_across_calendars() {
+  use crate::parsers::parse_date_value;
+
+  // Arrange
+  let parse = |input| parse_date_value(input).unwrap().1;
+  let julian = parse("@#DJULIAN@ 2 SEP 1752");
+  let gregorian = parse("14 SEP 1752");
+
+  // Act
+  let comparison = julian.compare(&gregorian);
+
+  // Assert
+  assert_eq!(comparison, DateComparison::Before);
+}
+
+#[test]
+fn sort_chronologically() {
+  use crate::parsers::parse_date_value;
+
+  // Arrange
+  let parse = |input| parse_date_value(input).unwrap().1;
+  let mut dates: Vec<DateValue> = vec![
+    parse("1 JAN 1900"),
+    parse("(unknown)"),
+    parse("JUN 1899"),
+    parse("1899"),
+    parse("BEF 1850"),
+  ];
+
+  // Act
+  dates.sort_by(DateValue::cmp_chronologically);
+
+  // Assert
+  assert_eq!(
+    dates,
+    vec![
+      parse("BEF 1850"),
+      parse("(unknown)"),
+      parse("1899"),
+      parse("JUN 1899"),
+      parse("1 JAN 1900"),
+    ]
+  );
+}
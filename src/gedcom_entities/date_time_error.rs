@@ -0,0 +1,82 @@
+use super::{FrenchMonth, HebrewMonth, Month};
+use std::fmt::{Display, Formatter};
+
+/// Why a date or time is impossible, such as `31 FEB 2020` or `25:99`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum DateTimeError {
+  InvalidDay {
+    day: u8,
+    month: Month,
+    year: i16,
+  },
+  InvalidHebrewDay {
+    day: u8,
+    month: HebrewMonth,
+    year: i16,
+  },
+  InvalidFrenchDay {
+    day: u8,
+    month: FrenchMonth,
+    year: i16,
+  },
+  HebrewMonthNotInYear {
+    month: HebrewMonth,
+    year: i16,
+  },
+  DayWithoutMonth,
+  YearZero,
+  InvalidDualYear {
+    year: i16,
+    dual_year: i16,
+  },
+  InvalidHours(u8),
+  InvalidMinutes(u8),
+  InvalidSeconds(u8),
+  InvalidFractionOfSecond(u32),
+}
+
+impl Display for DateTimeError {
+  fn fmt(&self, formatter: &mut Formatter) -> std::fmt::Result {
+    match self {
+      DateTimeError::InvalidDay { day, month, year } => {
+        write!(formatter, "Day {} is not in {} {}", day, month, year)
+      }
+      DateTimeError::InvalidHebrewDay { day, month, year } => {
+        write!(formatter, "Day {} is not in {} {}", day, month, year)
+      }
+      DateTimeError::InvalidFrenchDay { day, month, year } => {
+        write!(formatter, "Day {} is not in {} {}", day, month, year)
+      }
+      DateTimeError::HebrewMonthNotInYear { month, year } => {
+        write!(
+          formatter,
+          "{} is only in leap years, and {} is not one",
+          month, year
+        )
+      }
+      DateTimeError::DayWithoutMonth => formatter.write_str("Date has a day but no month"),
+      DateTimeError::YearZero => {
+        formatter.write_str("There is no year 0, 1 B.C. is followed by 1 A.D.")
+      }
+      DateTimeError::InvalidDualYear { year, dual_year } => write!(
+        formatter,
+        "Dual year {} should be the year after {}",
+        dual_year, year
+      ),
+      DateTimeError::InvalidHours(hours) => write!(formatter, "Hours {} are not 0 to 23", hours),
+      DateTimeError::InvalidMinutes(minutes) => {
+        write!(formatter, "Minutes {} are not 0 to 59", minutes)
+      }
+      DateTimeError::InvalidSeconds(seconds) => {
+        write!(formatter, "Seconds {} are not 0 to 59", seconds)
+      }
+      DateTimeError::InvalidFractionOfSecond(nanoseconds) => write!(
+        formatter,
+        "Fraction of a second {}ns needs whole seconds and must be under 1s",
+        nanoseconds
+      ),
+    }
+  }
+}
+
+impl std::error::Error for DateTimeError {}
@@ -1,5 +1,10 @@
-use crate::parsers::{parse_date_exact, parse_date_greg};
-use serde::{Deserialize, Deserializer};
+use super::{
+  julian_day_number::{french_month_length, hebrew_month_length, is_hebrew_leap_year},
+  DateTimeError, TimeValue,
+};
+use crate::parsers::{parse_date_exact, parse_date_greg, parse_date_value};
+use chrono::{NaiveDate, NaiveDateTime};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::fmt::{Display, Formatter};
 
 #[derive(Debug, PartialEq)]
@@ -9,6 +14,36 @@ pub struct DateExact {
   pub year: i16,
 }
 
+impl DateExact {
+  pub fn new(day: u8, month: Month, year: i16) -> Result<DateExact, DateTimeError> {
+    validate_gregorian_date(day, month, year)?;
+    Ok(DateExact { day, month, year })
+  }
+
+  pub fn to_naive_date(&self) -> Option<NaiveDate> {
+    to_naive_date(self.day, self.month, self.year)
+  }
+
+  pub fn to_naive_date_time(&self, time: &TimeValue) -> Option<NaiveDateTime> {
+    Some(self.to_naive_date()?.and_time(time.to_naive_time()?))
+  }
+}
+
+impl Display for DateExact {
+  fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(formatter, "{} {} {}", self.day, self.month, self.year)
+  }
+}
+
+impl Serialize for DateExact {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: Serializer,
+  {
+    serializer.collect_str(self)
+  }
+}
+
 pub fn deserialise_date_exact<'de, D>(deserializer: D) -> Result<DateExact, D::Error>
 where
   D: Deserializer<'de>,
@@ -25,11 +60,76 @@ where
   }
 }
 
+/// A Gregorian date, which may be given as just a month and year, or just a year.
+///
+/// Years before the common era are negative, so `44 B.C.` has a year of -44.
 #[derive(Debug, PartialEq)]
 pub struct DateGreg {
-  pub day: u8,
-  pub month: Month,
+  pub day: Option<u8>,
+  pub month: Option<Month>,
   pub year: i16,
+  /// The new-style year of a dual-dated year, such as 1732 for `1731/32`. Before the start of the
+  /// year moved from 25 March to 1 January, dates early in the year were often written with both.
+  pub dual_year: Option<i16>,
+}
+
+impl DateGreg {
+  pub fn new(
+    day: Option<u8>,
+    month: Option<Month>,
+    year: i16,
+    dual_year: Option<i16>,
+  ) -> Result<DateGreg, DateTimeError> {
+    let date = DateGreg {
+      day,
+      month,
+      year,
+      dual_year,
+    };
+    if year == 0 {
+      return Err(DateTimeError::YearZero);
+    }
+    if let Some(dual_year) = dual_year {
+      if i32::from(dual_year) != i32::from(year) + 1 {
+        return Err(DateTimeError::InvalidDualYear { year, dual_year });
+      }
+    }
+    match (day, month) {
+      (Some(day), Some(month)) => validate_gregorian_date(day, month, date.new_style_year())?,
+      (Some(_), None) => return Err(DateTimeError::DayWithoutMonth),
+      (None, _) => {}
+    }
+    Ok(date)
+  }
+
+  /// Only complete dates can be converted.
+  pub fn to_naive_date(&self) -> Option<NaiveDate> {
+    to_naive_date(self.day?, self.month?, self.new_style_year())
+  }
+
+  /// The year as it would be counted today, which is the new-style year of dual-dated years.
+  pub fn new_style_year(&self) -> i16 {
+    self.dual_year.unwrap_or(self.year)
+  }
+}
+
+impl Display for DateGreg {
+  fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+    write_day_and_month(formatter, self.day, self.month.as_ref())?;
+    match self.dual_year {
+      Some(dual_year) => write!(formatter, "{}/{:02}", self.year, dual_year % 100),
+      None => write_year(formatter, self.year),
+    }
+  }
+}
+
+impl Serialize for DateGreg {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: Serializer,
+  {
+    serializer.collect_str(self)
+  }
 }
 
 pub fn deserialise_date_greg<'de, D>(deserializer: D) -> Result<DateGreg, D::Error>
@@ -48,7 +148,317 @@ where
   }
 }
 
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, PartialEq)]
+pub struct DateJuln {
+  pub day: Option<u8>,
+  pub month: Option<Month>,
+  pub year: i16,
+}
+
+impl DateJuln {
+  pub fn new(day: Option<u8>, month: Option<Month>, year: i16) -> Result<DateJuln, DateTimeError> {
+    if year == 0 {
+      return Err(DateTimeError::YearZero);
+    }
+    validate_day(
+      day,
+      month,
+      |month| days_in_month(month, is_julian_leap_year(year)).into(),
+      |day, month| DateTimeError::InvalidDay { day, month, year },
+    )?;
+    Ok(DateJuln { day, month, year })
+  }
+}
+
+impl Display for DateJuln {
+  fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+    write_day_month_and_year(formatter, self.day, self.month.as_ref(), self.year)
+  }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct DateHebr {
+  pub day: Option<u8>,
+  pub month: Option<HebrewMonth>,
+  pub year: i16,
+}
+
+impl DateHebr {
+  /// The second month of Adar, `ADS`, is only in leap years.
+  pub fn new(
+    day: Option<u8>,
+    month: Option<HebrewMonth>,
+    year: i16,
+  ) -> Result<DateHebr, DateTimeError> {
+    if month == Some(HebrewMonth::AdarSheni) && !is_hebrew_leap_year(year.into()) {
+      return Err(DateTimeError::HebrewMonthNotInYear {
+        month: HebrewMonth::AdarSheni,
+        year,
+      });
+    }
+    validate_day(
+      day,
+      month,
+      |month| hebrew_month_length(year.into(), month),
+      |day, month| DateTimeError::InvalidHebrewDay { day, month, year },
+    )?;
+    Ok(DateHebr { day, month, year })
+  }
+}
+
+impl Display for DateHebr {
+  fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+    write_day_month_and_year(formatter, self.day, self.month.as_ref(), self.year)
+  }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct DateFren {
+  pub day: Option<u8>,
+  pub month: Option<FrenchMonth>,
+  pub year: i16,
+}
+
+impl DateFren {
+  pub fn new(
+    day: Option<u8>,
+    month: Option<FrenchMonth>,
+    year: i16,
+  ) -> Result<DateFren, DateTimeError> {
+    validate_day(
+      day,
+      month,
+      |month| french_month_length(year.into(), month),
+      |day, month| DateTimeError::InvalidFrenchDay { day, month, year },
+    )?;
+    Ok(DateFren { day, month, year })
+  }
+}
+
+impl Display for DateFren {
+  fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+    write_day_month_and_year(formatter, self.day, self.month.as_ref(), self.year)
+  }
+}
+
+fn validate_gregorian_date(day: u8, month: Month, year: i16) -> Result<(), DateTimeError> {
+  if year == 0 {
+    Err(DateTimeError::YearZero)
+  } else if day == 0 || day > days_in_gregorian_month(month, year) {
+    Err(DateTimeError::InvalidDay { day, month, year })
+  } else {
+    Ok(())
+  }
+}
+
+/// Checks that a day is in its month, for the calendars other than the Gregorian one.
+fn validate_day<M: Copy>(
+  day: Option<u8>,
+  month: Option<M>,
+  days_in_month: impl Fn(M) -> i32,
+  invalid_day: impl Fn(u8, M) -> DateTimeError,
+) -> Result<(), DateTimeError> {
+  match (day, month) {
+    (Some(day), Some(month)) if day == 0 || i32::from(day) > days_in_month(month) => {
+      Err(invalid_day(day, month))
+    }
+    (Some(_), None) => Err(DateTimeError::DayWithoutMonth),
+    _ => Ok(()),
+  }
+}
+
+fn days_in_gregorian_month(month: Month, year: i16) -> u8 {
+  days_in_month(month, is_gregorian_leap_year(year))
+}
+
+/// The Julian and Gregorian calendars only differ in which years are leap years.
+fn days_in_month(month: Month, is_leap_year: bool) -> u8 {
+  match month {
+    April | June | September | November => 30,
+    February if is_leap_year => 29,
+    February => 28,
+    _ => 31,
+  }
+}
+
+/// Every fourth year is a leap year, counted from 1 B.C., as in the Gregorian calendar.
+fn is_julian_leap_year(year: i16) -> bool {
+  let year = if year < 0 {
+    i32::from(year) + 1
+  } else {
+    i32::from(year)
+  };
+  year % 4 == 0
+}
+
+/// Leap years are counted from 1 B.C., the astronomical year 0, which was a leap year.
+fn is_gregorian_leap_year(year: i16) -> bool {
+  let year = if year < 0 {
+    i32::from(year) + 1
+  } else {
+    i32::from(year)
+  };
+  year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+/// Chrono uses astronomical year numbering, in which 1 B.C. is year 0.
+fn to_naive_date(day: u8, month: Month, year: i16) -> Option<NaiveDate> {
+  let year = if year < 0 {
+    i32::from(year) + 1
+  } else {
+    i32::from(year)
+  };
+  NaiveDate::from_ymd_opt(year, month.number(), day.into())
+}
+
+/// Writes the parts of a calendar date that are present, in the order `day month year`.
+fn write_day_month_and_year(
+  formatter: &mut Formatter<'_>,
+  day: Option<u8>,
+  month: Option<&impl Display>,
+  year: i16,
+) -> std::fmt::Result {
+  write_day_and_month(formatter, day, month)?;
+  write_year(formatter, year)
+}
+
+fn write_day_and_month(
+  formatter: &mut Formatter<'_>,
+  day: Option<u8>,
+  month: Option<&impl Display>,
+) -> std::fmt::Result {
+  if let Some(day) = day {
+    write!(formatter, "{} ", day)?;
+  }
+  if let Some(month) = month {
+    write!(formatter, "{} ", month)?;
+  }
+  Ok(())
+}
+
+fn write_year(formatter: &mut Formatter<'_>, year: i16) -> std::fmt::Result {
+  if year < 0 {
+    write!(formatter, "{} B.C.", -year)
+  } else {
+    write!(formatter, "{}", year)
+  }
+}
+
+/// A date in one of the calendars supported by Gedcom, given by a calendar escape such as
+/// `@#DJULIAN@`. Dates without an escape are Gregorian.
+#[derive(Debug, PartialEq)]
+pub enum Date {
+  Gregorian(DateGreg),
+  Julian(DateJuln),
+  Hebrew(DateHebr),
+  French(DateFren),
+}
+
+impl Display for Date {
+  fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Date::Gregorian(date) => write!(formatter, "{}", date),
+      Date::Julian(date) => write!(formatter, "@#DJULIAN@ {}", date),
+      Date::Hebrew(date) => write!(formatter, "@#DHEBREW@ {}", date),
+      Date::French(date) => write!(formatter, "@#DFRENCH R@ {}", date),
+    }
+  }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum DateValue {
+  Date(Date),
+  Approximated(DateApproximated),
+  Range(DateRange),
+  Period(DatePeriod),
+  Interpreted(Date, String),
+  Phrase(String),
+  /// Free text that does not follow the date grammar, such as `Spring 1850`, kept as it was
+  /// written.
+  Unparsed(String),
+}
+
+#[derive(Debug, PartialEq)]
+pub enum DateApproximated {
+  About(Date),
+  Calculated(Date),
+  Estimated(Date),
+}
+
+#[derive(Debug, PartialEq)]
+pub enum DateRange {
+  Before(Date),
+  After(Date),
+  Between(Date, Date),
+}
+
+#[derive(Debug, PartialEq)]
+pub enum DatePeriod {
+  From(Date),
+  To(Date),
+  FromTo(Date, Date),
+}
+
+impl Display for DateValue {
+  fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+    match self {
+      DateValue::Date(date) => write!(formatter, "{}", date),
+      DateValue::Approximated(DateApproximated::About(date)) => write!(formatter, "ABT {}", date),
+      DateValue::Approximated(DateApproximated::Calculated(date)) => {
+        write!(formatter, "CAL {}", date)
+      }
+      DateValue::Approximated(DateApproximated::Estimated(date)) => {
+        write!(formatter, "EST {}", date)
+      }
+      DateValue::Range(DateRange::Before(date)) => write!(formatter, "BEF {}", date),
+      DateValue::Range(DateRange::After(date)) => write!(formatter, "AFT {}", date),
+      DateValue::Range(DateRange::Between(start, end)) => {
+        write!(formatter, "BET {} AND {}", start, end)
+      }
+      DateValue::Period(DatePeriod::From(date)) => write!(formatter, "FROM {}", date),
+      DateValue::Period(DatePeriod::To(date)) => write!(formatter, "TO {}", date),
+      DateValue::Period(DatePeriod::FromTo(start, end)) => {
+        write!(formatter, "FROM {} TO {}", start, end)
+      }
+      DateValue::Interpreted(date, phrase) => write!(formatter, "INT {} ({})", date, phrase),
+      DateValue::Phrase(phrase) => write!(formatter, "({})", phrase),
+      DateValue::Unparsed(text) => formatter.write_str(text),
+    }
+  }
+}
+
+impl Serialize for DateValue {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: Serializer,
+  {
+    serializer.collect_str(self)
+  }
+}
+
+/// Dates that cannot be parsed are kept as a `DateValue::Unparsed`, as exports are full of free-text
+/// dates (_e.g._ `Spring 1850`) that are still useful to a reader.
+pub fn deserialise_date_value<'de, D>(deserializer: D) -> Result<DateValue, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  let date_as_string = String::deserialize(deserializer)?;
+  match parse_date_value(&date_as_string) {
+    Ok(("", date)) => Ok(date),
+    _ => Ok(DateValue::Unparsed(date_as_string)),
+  }
+}
+
+pub fn deserialise_optional_date_value<'de, D>(
+  deserializer: D,
+) -> Result<Option<DateValue>, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  deserialise_date_value(deserializer).map(Some)
+}
+
+#[derive(Copy, Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub enum Month {
   January,
   February,
@@ -66,21 +476,253 @@ pub enum Month {
 
 use Month::*;
 
+impl Month {
+  /// The number of the month, from 1 for January to 12 for December.
+  pub fn number(self) -> u32 {
+    match self {
+      January => 1,
+      February => 2,
+      March => 3,
+      April => 4,
+      May => 5,
+      June => 6,
+      July => 7,
+      August => 8,
+      September => 9,
+      October => 10,
+      November => 11,
+      December => 12,
+    }
+  }
+}
+
 impl Display for Month {
   fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
     match self {
-      January => write!(formatter, "Jan"),
-      February => write!(formatter, "Feb"),
-      March => write!(formatter, "Mar"),
-      April => write!(formatter, "Apr"),
-      May => write!(formatter, "May"),
-      June => write!(formatter, "Jun"),
-      July => write!(formatter, "Jul"),
-      August => write!(formatter, "Aug"),
-      September => write!(formatter, "Sep"),
-      October => write!(formatter, "Nov"),
-      November => write!(formatter, "Oct"),
-      December => write!(formatter, "Dec"),
+      January => write!(formatter, "JAN"),
+      February => write!(formatter, "FEB"),
+      March => write!(formatter, "MAR"),
+      April => write!(formatter, "APR"),
+      May => write!(formatter, "MAY"),
+      June => write!(formatter, "JUN"),
+      July => write!(formatter, "JUL"),
+      August => write!(formatter, "AUG"),
+      September => write!(formatter, "SEP"),
+      October => write!(formatter, "OCT"),
+      November => write!(formatter, "NOV"),
+      December => write!(formatter, "DEC"),
     }
   }
 }
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum HebrewMonth {
+  Tishri,
+  Cheshvan,
+  Kislev,
+  Tevet,
+  Shevat,
+  Adar,
+  AdarSheni,
+  Nisan,
+  Iyar,
+  Sivan,
+  Tammuz,
+  Av,
+  Elul,
+}
+
+impl Display for HebrewMonth {
+  fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+    formatter.write_str(match self {
+      HebrewMonth::Tishri => "TSH",
+      HebrewMonth::Cheshvan => "CSH",
+      HebrewMonth::Kislev => "KSL",
+      HebrewMonth::Tevet => "TVT",
+      HebrewMonth::Shevat => "SHV",
+      HebrewMonth::Adar => "ADR",
+      HebrewMonth::AdarSheni => "ADS",
+      HebrewMonth::Nisan => "NSN",
+      HebrewMonth::Iyar => "IYR",
+      HebrewMonth::Sivan => "SVN",
+      HebrewMonth::Tammuz => "TMZ",
+      HebrewMonth::Av => "AAV",
+      HebrewMonth::Elul => "ELL",
+    })
+  }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum FrenchMonth {
+  Vendemiaire,
+  Brumaire,
+  Frimaire,
+  Nivose,
+  Pluviose,
+  Ventose,
+  Germinal,
+  Floreal,
+  Prairial,
+  Messidor,
+  Thermidor,
+  Fructidor,
+  JoursComplementaires,
+}
+
+impl Display for FrenchMonth {
+  fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+    formatter.write_str(match self {
+      FrenchMonth::Vendemiaire => "VEND",
+      FrenchMonth::Brumaire => "BRUM",
+      FrenchMonth::Frimaire => "FRIM",
+      FrenchMonth::Nivose => "NIVO",
+      FrenchMonth::Pluviose => "PLUV",
+      FrenchMonth::Ventose => "VENT",
+      FrenchMonth::Germinal => "GERM",
+      FrenchMonth::Floreal => "FLOR",
+      FrenchMonth::Prairial => "PRAI",
+      FrenchMonth::Messidor => "MESS",
+      FrenchMonth::Thermidor => "THER",
+      FrenchMonth::Fructidor => "FRUC",
+      FrenchMonth::JoursComplementaires => "COMP",
+    })
+  }
+}
+
+#[test]
+fn date_greg_rejects_impossible_dates() {
+  assert_eq!(
+    DateGreg::new(Some(31), Some(April), 1900, None),
+    Err(DateTimeError::InvalidDay {
+      day: 31,
+      month: April,
+      year: 1900
+    })
+  );
+  assert_eq!(
+    DateGreg::new(Some(1), None, 1900, None),
+    Err(DateTimeError::DayWithoutMonth)
+  );
+  assert_eq!(
+    DateGreg::new(None, None, 1731, Some(1733)),
+    Err(DateTimeError::InvalidDualYear {
+      year: 1731,
+      dual_year: 1733
+    })
+  );
+  // 1735/36 is a leap year, because February falls in 1736.
+  assert!(DateGreg::new(Some(29), Some(February), 1735, Some(1736)).is_ok());
+}
+
+#[test]
+fn date_to_naive_date_time() {
+  let date = DateExact::new(15, April, 2020).unwrap();
+  let time = TimeValue::new(15, 21, Some(24), None).unwrap();
+  let before_common_era = DateGreg::new(Some(1), Some(January), -1, None).unwrap();
+  assert_eq!(
+    date.to_naive_date_time(&time),
+    Some(
+      NaiveDate::from_ymd_opt(2020, 4, 15)
+        .unwrap()
+        .and_hms_opt(15, 21, 24)
+        .unwrap()
+    )
+  );
+  assert_eq!(
+    before_common_era.to_naive_date(),
+    NaiveDate::from_ymd_opt(0, 1, 1)
+  );
+  assert_eq!(
+    DateGreg::new(None, Some(June), 1890, None)
+      .unwrap()
+      .to_naive_date(),
+    None
+  );
+}
+
+#[test]
+fn display_dual_year_and_before_common_era() {
+  let dual_dated = DateGreg {
+    day: None,
+    month: None,
+    year: 1699,
+    dual_year: Some(1700),
+  };
+  let before_common_era = DateGreg {
+    day: None,
+    month: None,
+    year: -44,
+    dual_year: None,
+  };
+  assert_eq!(dual_dated.to_string(), "1699/00");
+  assert_eq!(before_common_era.to_string(), "44 B.C.");
+}
+
+#[test]
+fn deserialise_and_format_date_values_that_are_not_dates() {
+  use crate::gedcom_data_format::from_str;
+
+  #[derive(Deserialize, PartialEq, Debug)]
+  struct Event {
+    #[serde(rename = "DATE", deserialize_with = "deserialise_date_value")]
+    date: DateValue,
+  }
+
+  let inputs_and_dates = [
+    (
+      "Spring 1850",
+      DateValue::Unparsed("Spring 1850".to_string()),
+    ),
+    (
+      "(Spring 1850)",
+      DateValue::Phrase("Spring 1850".to_string()),
+    ),
+  ];
+  for (date_text, expected_date) in inputs_and_dates.iter() {
+    let input = format!("0 BIRT\n1 DATE {}\n", date_text);
+    let result: Event = from_str(&input).expect("No errors during this test");
+    assert_eq!(&result.date, expected_date);
+    assert_eq!(&result.date.to_string(), date_text);
+  }
+}
+
+#[test]
+fn format_date_value_round_trip() {
+  let inputs_and_canonical_text = [
+    ("15 apr 2020", "15 APR 2020"),
+    ("abt 3 oct 1850", "ABT 3 OCT 1850"),
+    ("CAL NOV 1901", "CAL NOV 1901"),
+    ("EST 44 B.C.", "EST 44 B.C."),
+    ("BEF 05 DEC 1899", "BEF 5 DEC 1899"),
+    (
+      "BET @#DJULIAN@ 2 SEP 1752 AND 14 SEP 1752",
+      "BET @#DJULIAN@ 2 SEP 1752 AND 14 SEP 1752",
+    ),
+    (
+      "FROM @#DGREGORIAN@ 11 FEB 1731/32 TO 1740",
+      "FROM 11 FEB 1731/32 TO 1740",
+    ),
+    ("@#DHEBREW@ 15 NSN 5779", "@#DHEBREW@ 15 NSN 5779"),
+    ("@#DFRENCH R@ COMP 11", "@#DFRENCH R@ COMP 11"),
+    (
+      "INT 3 NOV 1901 (Third of November)",
+      "INT 3 NOV 1901 (Third of November)",
+    ),
+    ("(Sometime in the spring)", "(Sometime in the spring)"),
+  ];
+  for (input, canonical_text) in inputs_and_canonical_text.iter() {
+    let (_, date) = parse_date_value(input).expect("No errors during this test");
+    assert_eq!(&date.to_string(), canonical_text);
+    let (remaining_text, reparsed_date) =
+      parse_date_value(canonical_text).expect("No errors during this test");
+    assert_eq!(remaining_text, "");
+    assert_eq!(reparsed_date, date);
+  }
+}
+
+#[test]
+fn format_date_exact_round_trip() {
+  let (_, date) = parse_date_exact("9 jan 2020").expect("No errors during this test");
+  assert_eq!(date.to_string(), "9 JAN 2020");
+  assert_eq!(parse_date_exact(&date.to_string()), Ok(("", date)));
+}
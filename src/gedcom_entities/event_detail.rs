@@ -0,0 +1,100 @@
+use super::{
+  deserialise_optional_date_value, deserialize_boolean, Address, DateValue, MultimediaLink, Note,
+  Place, SourceCitation,
+};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+/// The details shared by every individual and family event.
+#[derive(Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct EventDetail<'input> {
+  /// `Y` for an event known to have happened without any other details, or the descriptor of a
+  /// generic `EVEN`.
+  #[serde(borrow, rename = "LINE_VALUE", default)]
+  pub value: Option<Cow<'input, str>>,
+  #[serde(borrow, rename = "TYPE")]
+  pub event_type: Option<Cow<'input, str>>,
+  #[serde(
+    rename = "DATE",
+    default,
+    deserialize_with = "deserialise_optional_date_value"
+  )]
+  pub date: Option<DateValue>,
+  #[serde(borrow, rename = "PLAC")]
+  pub place: Option<Place<'input>>,
+  #[serde(borrow, rename = "ADDR")]
+  pub address: Option<Address<'input>>,
+  #[serde(rename = "PHON", default)]
+  pub phone_numbers: Vec<&'input str>,
+  #[serde(rename = "EMAIL", default)]
+  pub email_addresses: Vec<&'input str>,
+  #[serde(rename = "FAX", default)]
+  pub fax_numbers: Vec<&'input str>,
+  #[serde(rename = "WWW", default)]
+  pub web_pages: Vec<&'input str>,
+  /// The age of the individual at the time of the event, such as `42y 3m`. Only for individual
+  /// events.
+  #[serde(rename = "AGE")]
+  pub age: Option<&'input str>,
+  #[serde(borrow, rename = "AGNC")]
+  pub agency: Option<Cow<'input, str>>,
+  #[serde(borrow, rename = "CAUS")]
+  pub cause: Option<Cow<'input, str>>,
+  #[serde(borrow, rename = "NOTE", default)]
+  pub notes: Vec<Note<'input>>,
+  #[serde(borrow, rename = "SOUR", default)]
+  pub sources: Vec<SourceCitation<'input>>,
+  #[serde(borrow, rename = "OBJE", default)]
+  pub multimedia_links: Vec<MultimediaLink<'input>>,
+  #[serde(
+    rename = "_PRIM",
+    default,
+    deserialize_with = "deserialize_boolean",
+    skip_serializing_if = "std::ops::Not::not"
+  )]
+  pub is_primary: bool,
+}
+
+/// Declares an enum with one `EventDetail` variant per tag, along with `TAGS`, the list of those
+/// tags, `detail`, which gets at the detail of any variant, and `tag`, which gets its tag. Generating all three from the one
+/// list of variants keeps the tags that are dispatched on in step with the tags that are read.
+macro_rules! event_detail_enum {
+  (
+    $(#[$enum_attribute:meta])*
+    pub enum $name:ident {
+      $(
+        $(#[$variant_attribute:meta])*
+        $variant:ident = $tag:literal,
+      )*
+    }
+  ) => {
+    $(#[$enum_attribute])*
+    #[derive(Debug, serde::Deserialize, PartialEq, serde::Serialize)]
+    pub enum $name<'input> {
+      $(
+        $(#[$variant_attribute])*
+        #[serde(borrow, rename = $tag)]
+        $variant($crate::gedcom_entities::EventDetail<'input>),
+      )*
+    }
+
+    impl<'input> $name<'input> {
+      /// The tag of every variant.
+      pub const TAGS: &'static [&'static str] = &[$($tag),*];
+
+      pub fn detail(&self) -> &$crate::gedcom_entities::EventDetail<'input> {
+        match self {
+          $($name::$variant(detail))|* => detail,
+        }
+      }
+
+      pub fn tag(&self) -> &'static str {
+        match self {
+          $($name::$variant(_) => $tag,)*
+        }
+      }
+    }
+  };
+}
+
+pub(super) use event_detail_enum;
@@ -0,0 +1,129 @@
+use super::{ChangeDate, FamilyEvent, MultimediaLink, Note};
+use serde::{
+  de::{self, IgnoredAny, MapAccess, Visitor},
+  Deserialize, Deserializer, Serialize,
+};
+use std::{fmt::Formatter, marker::PhantomData};
+
+#[derive(Debug, PartialEq, Serialize)]
+pub struct Family<'input> {
+  #[serde(rename = "XREF_ID")]
+  pub xref_id: &'input str,
+  #[serde(rename = "HUSB")]
+  pub husband: Option<&'input str>,
+  #[serde(rename = "WIFE")]
+  pub wife: Option<&'input str>,
+  #[serde(rename = "CHIL")]
+  pub children: Vec<&'input str>,
+  #[serde(rename = "NCHI")]
+  pub number_of_children: Option<u16>,
+  /// Events in document order. As with the events of an individual, the Gedcom serializer writes
+  /// each event under its own tag rather than under the `events` key.
+  pub events: Vec<FamilyEvent<'input>>,
+  #[serde(rename = "OBJE")]
+  pub multimedia_links: Vec<MultimediaLink<'input>>,
+  #[serde(rename = "NOTE")]
+  pub notes: Vec<Note<'input>>,
+  #[serde(rename = "_UID")]
+  pub uid: Option<&'input str>,
+  #[serde(rename = "CHAN")]
+  pub change_date: Option<ChangeDate>,
+}
+
+const FAMILY_FIELDS: &[&str] = &[
+  "XREF_ID", "HUSB", "WIFE", "CHIL", "NCHI", "OBJE", "NOTE", "_UID", "CHAN",
+];
+
+// As with individuals, events of different kinds are interleaved and may repeat, and so may the
+// other lists, such as children with a marriage between them.
+impl<'de: 'input, 'input> Deserialize<'de> for Family<'input> {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    deserializer.deserialize_struct("Family", FAMILY_FIELDS, FamilyVisitor(PhantomData))
+  }
+}
+
+struct FamilyVisitor<'input>(PhantomData<Family<'input>>);
+
+impl<'de: 'input, 'input> Visitor<'de> for FamilyVisitor<'input> {
+  type Value = Family<'input>;
+
+  fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+    formatter.write_str("a FAM record")
+  }
+
+  fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+  where
+    A: MapAccess<'de>,
+  {
+    let mut xref_id = None;
+    let mut husband = None;
+    let mut wife = None;
+    let mut children = vec![];
+    let mut number_of_children = None;
+    let mut events = vec![];
+    let mut multimedia_links = vec![];
+    let mut notes = vec![];
+    let mut uid = None;
+    let mut change_date = None;
+
+    while let Some(key) = map.next_key::<&str>()? {
+      match key {
+        "XREF_ID" => xref_id = Some(map.next_value()?),
+        "HUSB" => husband = Some(map.next_value()?),
+        "WIFE" => wife = Some(map.next_value()?),
+        "CHIL" => children.extend(map.next_value::<Vec<&str>>()?),
+        "NCHI" => number_of_children = Some(map.next_value()?),
+        "OBJE" => multimedia_links.extend(map.next_value::<Vec<_>>()?),
+        "NOTE" => notes.extend(map.next_value::<Vec<_>>()?),
+        "_UID" => uid = Some(map.next_value()?),
+        "CHAN" => change_date = Some(map.next_value()?),
+        tag if FamilyEvent::TAGS.contains(&tag) => events.push(map.next_value()?),
+        _ => {
+          map.next_value::<IgnoredAny>()?;
+        }
+      }
+    }
+
+    Ok(Family {
+      xref_id: xref_id.ok_or_else(|| de::Error::missing_field("XREF_ID"))?,
+      husband,
+      wife,
+      children,
+      number_of_children,
+      events,
+      multimedia_links,
+      notes,
+      uid,
+      change_date,
+    })
+  }
+}
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+pub struct ChildToFamilyLink<'input> {
+  #[serde(rename = "FAMC")]
+  pub family: &'input str,
+  #[serde(rename = "PEDI")]
+  pub pedigree: Option<Pedigree>,
+}
+
+#[derive(Copy, Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub enum Pedigree {
+  #[serde(rename = "adopted")]
+  Adopted,
+  #[serde(rename = "birth")]
+  Birth,
+  #[serde(rename = "foster")]
+  Foster,
+  #[serde(rename = "sealing")]
+  Sealing,
+}
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+pub struct SpouseToFamilyLink<'input> {
+  #[serde(rename = "FAMS")]
+  pub family: &'input str,
+}
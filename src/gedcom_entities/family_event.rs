@@ -0,0 +1,19 @@
+use super::event_detail::event_detail_enum;
+
+event_detail_enum! {
+  /// An event in the life of a family. A generic `EVEN` is described by its `TYPE`.
+  pub enum FamilyEvent {
+    Annulment = "ANUL",
+    Census = "CENS",
+    Divorce = "DIV",
+    DivorceFiled = "DIVF",
+    Engagement = "ENGA",
+    MarriageBann = "MARB",
+    MarriageContract = "MARC",
+    Marriage = "MARR",
+    MarriageLicense = "MARL",
+    MarriageSettlement = "MARS",
+    Residence = "RESI",
+    Event = "EVEN",
+  }
+}
@@ -1,28 +1,67 @@
-use super::{deserialise_date_exact, deserialise_time_value, DateExact, TimeValue};
-use serde::Deserialize;
+use super::{
+  deserialise_date_exact, deserialise_time_value, Address, DateExact, Individual, Record, TimeValue,
+};
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::{
+  borrow::Cow,
+  fmt::{Display, Formatter},
+};
 
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
 pub struct Header<'input> {
   #[serde(rename = "SOUR")]
   pub source: Source<'input>,
   #[serde(rename = "DEST")]
-  pub receiving_sytem_name: &'input str,
+  pub receiving_system_name: Option<&'input str>,
   #[serde(rename = "DATE")]
-  pub transmission_date_time: TransmissionDateTime,
+  pub transmission_date_time: Option<TransmissionDateTime>,
+  /// A pointer to the submitter record.
   #[serde(rename = "SUBM")]
-  pub submission_record_id: &'input str,
+  pub submitter_id: &'input str,
+  /// A pointer to the submission record.
+  #[serde(rename = "SUBN")]
+  pub submission_id: Option<&'input str>,
   #[serde(rename = "FILE")]
-  pub file_name: &'input str,
+  pub file_name: Option<&'input str>,
+  #[serde(borrow, rename = "COPR")]
+  pub copyright: Option<Cow<'input, str>>,
   #[serde(rename = "GEDC")]
   pub gedcom: Gedcom<'input>,
   #[serde(rename = "CHAR")]
-  pub character_set: CharacterSet,
+  pub character_encoding: CharacterEncoding<'input>,
   #[serde(rename = "LANG")]
-  pub language: Option<Language>,
+  pub language: Option<Language<'input>>,
+  #[serde(rename = "PLAC")]
+  pub place_hierarchy: Option<PlaceHierarchy<'input>>,
+  /// A description of what the file contains.
+  #[serde(borrow, rename = "NOTE")]
+  pub content_description: Option<Cow<'input, str>>,
+  /// A pointer to the home person of the tree, the individual it is centred on.
+  #[serde(rename = "_ROOT")]
+  pub root_individual_id: Option<&'input str>,
+}
+
+impl<'input> Header<'input> {
+  /// The home person of the tree. Returns `None` if there is no `_ROOT`, or it does not resolve to
+  /// an individual.
+  pub fn root_individual<'a>(
+    &self,
+    records: &'a [Record<'input>],
+  ) -> Option<&'a Individual<'input>> {
+    let root_individual_id = self.root_individual_id?;
+    records.iter().find_map(|record| match record {
+      Record::Individual(individual) if individual.xref_id == root_individual_id => {
+        Some(individual)
+      }
+      _ => None,
+    })
+  }
 }
 
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
 pub struct Source<'input> {
+  /// The registered name of the system that made the file.
   #[serde(rename = "SOUR")]
   pub id: &'input str,
   #[serde(rename = "NAME")]
@@ -31,39 +70,86 @@ pub struct Source<'input> {
   pub version: Option<&'input str>,
   #[serde(rename = "CORP")]
   pub business: Option<Business<'input>>,
+  #[serde(borrow, rename = "DATA")]
+  pub data: Option<ElectronicSource<'input>>,
+}
+
+/// The electronic source, such as a CD, that the data in the file came from.
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+pub struct ElectronicSource<'input> {
+  #[serde(borrow, rename = "DATA")]
+  pub name: Cow<'input, str>,
+  #[serde(
+    rename = "DATE",
+    default,
+    deserialize_with = "deserialise_optional_date_exact"
+  )]
+  pub publication_date: Option<DateExact>,
+  #[serde(borrow, rename = "COPR")]
+  pub copyright: Option<Cow<'input, str>>,
 }
 
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
 pub struct Business<'input> {
   #[serde(rename = "CORP")]
   pub name: &'input str,
   #[serde(rename = "ADDR")]
   pub address: Option<Address<'input>>,
-  #[serde(rename = "WWW")]
-  pub web_page: Vec<&'input str>,
-}
-
-#[derive(Debug, Deserialize, PartialEq)]
-pub struct Address<'input> {
-  #[serde(rename = "ADDR")]
-  pub address_line: &'input str,
-  #[serde(rename = "CITY")]
-  pub city: Option<&'input str>,
-  #[serde(rename = "POST")]
-  pub post_code: Option<&'input str>,
-  #[serde(rename = "CTRY")]
-  pub country: Option<&'input str>,
+  #[serde(rename = "PHON", default)]
+  pub phone_numbers: Vec<&'input str>,
+  #[serde(rename = "EMAIL", default)]
+  pub email_addresses: Vec<&'input str>,
+  #[serde(rename = "FAX", default)]
+  pub fax_numbers: Vec<&'input str>,
+  #[serde(rename = "WWW", default)]
+  pub web_pages: Vec<&'input str>,
 }
 
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
 pub struct TransmissionDateTime {
   #[serde(rename = "DATE", deserialize_with = "deserialise_date_exact")]
   pub date: DateExact,
-  #[serde(rename = "TIME", deserialize_with = "deserialise_time_value")]
-  pub time: TimeValue,
+  #[serde(
+    rename = "TIME",
+    default,
+    deserialize_with = "deserialise_optional_time_value"
+  )]
+  pub time: Option<TimeValue>,
 }
 
-#[derive(Debug, Deserialize, PartialEq)]
+impl TransmissionDateTime {
+  /// Files without a transmission time are taken to have been made at midnight.
+  pub fn to_naive_date_time(&self) -> Option<NaiveDateTime> {
+    match &self.time {
+      Some(time) => self.date.to_naive_date_time(time),
+      None => self.date.to_naive_date()?.and_hms_opt(0, 0, 0),
+    }
+  }
+}
+
+fn deserialise_optional_date_exact<'de, D>(deserializer: D) -> Result<Option<DateExact>, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  deserialise_date_exact(deserializer).map(Some)
+}
+
+fn deserialise_optional_time_value<'de, D>(deserializer: D) -> Result<Option<TimeValue>, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  deserialise_time_value(deserializer).map(Some)
+}
+
+/// The kinds of jurisdiction that place names in the file are made of.
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+pub struct PlaceHierarchy<'input> {
+  /// Such as `City, County, Country`.
+  #[serde(rename = "FORM")]
+  pub form: &'input str,
+}
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
 pub struct Gedcom<'input> {
   #[serde(rename = "VERS")]
   pub version_number: &'input str,
@@ -71,13 +157,22 @@ pub struct Gedcom<'input> {
   pub form: GedcomForm,
 }
 
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
 pub enum GedcomForm {
   #[serde(rename = "LINEAGE-LINKED")]
   LineageLinked,
 }
 
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+pub struct CharacterEncoding<'input> {
+  #[serde(rename = "CHAR")]
+  pub character_set: CharacterSet,
+  /// The version of the character set, such as the ANSEL version.
+  #[serde(rename = "VERS")]
+  pub version: Option<&'input str>,
+}
+
+#[derive(Copy, Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub enum CharacterSet {
   #[serde(rename = "ANSEL")]
   Ansel,
@@ -89,7 +184,144 @@ pub enum CharacterSet {
   Ascii,
 }
 
-#[derive(Debug, Deserialize, PartialEq)]
-pub enum Language {
-  English,
+/// Declares the `Language` enum from its variants and the names files spell them with, along
+/// with `Language::from_name`, which looks a name up.
+macro_rules! languages {
+  ($($variant:ident = $name:literal,)*) => {
+    /// The languages named in the Gedcom 5.5.1 specification, which are written as the
+    /// specification spells them. Any other language is kept as it was written.
+    #[derive(Copy, Clone, Debug, PartialEq)]
+    pub enum Language<'input> {
+      $($variant,)*
+      Other(&'input str),
+    }
+
+    impl<'input> Language<'input> {
+      /// Names are matched whatever their case, as files spell them `English` or `ENGLISH`.
+      pub fn from_name(name: &'input str) -> Self {
+        $(
+          if name.eq_ignore_ascii_case($name) {
+            return Language::$variant;
+          }
+        )*
+        Language::Other(name)
+      }
+    }
+
+    impl Display for Language<'_> {
+      fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        formatter.write_str(match self {
+          $(Language::$variant => $name,)*
+          Language::Other(name) => name,
+        })
+      }
+    }
+  };
+}
+
+languages! {
+  Afrikaans = "Afrikaans",
+  Albanian = "Albanian",
+  Amharic = "Amharic",
+  AngloSaxon = "Anglo-Saxon",
+  Arabic = "Arabic",
+  Armenian = "Armenian",
+  Assamese = "Assamese",
+  Belarusian = "Belorusian",
+  Bengali = "Bengali",
+  Braj = "Braj",
+  Bulgarian = "Bulgarian",
+  Burmese = "Burmese",
+  Cantonese = "Cantonese",
+  Catalan = "Catalan",
+  CatalanSpanish = "Catalan_Spn",
+  ChurchSlavic = "Church-Slavic",
+  Czech = "Czech",
+  Danish = "Danish",
+  Dogri = "Dogri",
+  Dutch = "Dutch",
+  English = "English",
+  Esperanto = "Esperanto",
+  Estonian = "Estonian",
+  Faroese = "Faroese",
+  Finnish = "Finnish",
+  French = "French",
+  Georgian = "Georgian",
+  German = "German",
+  Greek = "Greek",
+  Gujarati = "Gujarati",
+  Hawaiian = "Hawaiian",
+  Hebrew = "Hebrew",
+  Hindi = "Hindi",
+  Hungarian = "Hungarian",
+  Icelandic = "Icelandic",
+  Indonesian = "Indonesian",
+  Italian = "Italian",
+  Japanese = "Japanese",
+  Kannada = "Kannada",
+  Khmer = "Khmer",
+  Konkani = "Konkani",
+  Korean = "Korean",
+  Lahnda = "Lahnda",
+  Lao = "Lao",
+  Latvian = "Latvian",
+  Lithuanian = "Lithuanian",
+  Macedonian = "Macedonian",
+  Maithili = "Maithili",
+  Malayalam = "Malayalam",
+  Mandarin = "Mandrin",
+  Manipuri = "Manipuri",
+  Marathi = "Marathi",
+  Mewari = "Mewari",
+  Navajo = "Navaho",
+  Nepali = "Nepali",
+  Norwegian = "Norwegian",
+  Oriya = "Oriya",
+  Pahari = "Pahari",
+  Pali = "Pali",
+  Punjabi = "Panjabi",
+  Persian = "Persian",
+  Polish = "Polish",
+  Portuguese = "Portuguese",
+  Prakrit = "Prakrit",
+  Pashto = "Pusto",
+  Rajasthani = "Rajasthani",
+  Romanian = "Romanian",
+  Russian = "Russian",
+  Sanskrit = "Sanskrit",
+  Serb = "Serb",
+  SerboCroatian = "Serbo_Croa",
+  Slovak = "Slovak",
+  Slovene = "Slovene",
+  Spanish = "Spanish",
+  Swedish = "Swedish",
+  Tagalog = "Tagalog",
+  Tamil = "Tamil",
+  Telugu = "Telugu",
+  Thai = "Thai",
+  Tibetan = "Tibetan",
+  Turkish = "Turkish",
+  Ukrainian = "Ukrainian",
+  Urdu = "Urdu",
+  Vietnamese = "Vietnamese",
+  Wendic = "Wendic",
+  Yiddish = "Yiddish",
+}
+
+impl Serialize for Language<'_> {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: Serializer,
+  {
+    serializer.collect_str(self)
+  }
+}
+
+impl<'de: 'input, 'input> Deserialize<'de> for Language<'input> {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    <&str>::deserialize(deserializer).map(Language::from_name)
+  }
 }
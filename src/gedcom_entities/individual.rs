@@ -1,32 +1,125 @@
 use super::{
-  deserialise_date_exact, deserialise_date_greg, deserialise_time_value, DateExact, DateGreg,
-  TimeValue,
+  deserialise_date_exact, deserialise_time_value, ChildToFamilyLink, DateExact,
+  IndividualAttribute, IndividualEvent, MultimediaLink, Note, PersonalName, SourceCitation,
+  SpouseToFamilyLink, TimeValue,
 };
-use serde::{Deserialize, Deserializer};
+use chrono::NaiveDateTime;
+use serde::{
+  de::{self, IgnoredAny, MapAccess, Visitor},
+  Deserialize, Deserializer, Serialize,
+};
+use std::{fmt::Formatter, marker::PhantomData};
 
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, PartialEq, Serialize)]
 pub struct Individual<'input> {
-  #[serde(borrow, rename = "NAME")]
+  #[serde(rename = "XREF_ID")]
+  pub xref_id: &'input str,
+  #[serde(rename = "NAME")]
   pub name: Vec<PersonalName<'input>>,
   #[serde(rename = "SEX")]
   pub sex: Option<SexValue>,
-  // TODO model events more like the Gedcom spec - should probably be a vec of some enum.
-  #[serde(rename = "BIRT")]
-  pub birth_event: Option<BirthEvent<'input>>,
+  /// Events in document order. The Gedcom serializer never writes the `events` key: each event is
+  /// a newtype variant, which it writes under the variant's own tag, such as `BIRT`.
+  pub events: Vec<IndividualEvent<'input>>,
+  /// Attributes in document order. As with `events`, the Gedcom serializer writes each attribute
+  /// under its own tag rather than under the `attributes` key.
+  pub attributes: Vec<IndividualAttribute<'input>>,
+  #[serde(rename = "FAMC")]
+  pub child_to_family_links: Vec<ChildToFamilyLink<'input>>,
+  #[serde(rename = "FAMS")]
+  pub spouse_to_family_links: Vec<SpouseToFamilyLink<'input>>,
+  #[serde(rename = "SOUR")]
+  pub sources: Vec<SourceCitation<'input>>,
+  #[serde(rename = "OBJE")]
+  pub multimedia_links: Vec<MultimediaLink<'input>>,
+  #[serde(rename = "NOTE")]
+  pub notes: Vec<Note<'input>>,
   #[serde(rename = "_UID")]
   pub uid: Option<&'input str>,
   #[serde(rename = "CHAN")]
   pub change_date: Option<ChangeDate>,
 }
 
-#[derive(Debug, Deserialize, PartialEq)]
-pub struct PersonalName<'input> {
-  #[serde(rename = "GIVN")]
-  pub given_name: &'input str,
-  #[serde(rename = "SURN")]
-  pub surname: &'input str,
-  #[serde(rename = "_PRIM", deserialize_with = "deserialize_boolean")]
-  pub is_primary: bool,
+const INDIVIDUAL_FIELDS: &[&str] = &[
+  "XREF_ID", "NAME", "SEX", "FAMC", "FAMS", "SOUR", "OBJE", "NOTE", "_UID", "CHAN",
+];
+
+// Events and attributes of different kinds are interleaved and may repeat, which a derived
+// implementation would reject as duplicate fields, so each kind is collected into one list.
+impl<'de: 'input, 'input> Deserialize<'de> for Individual<'input> {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    deserializer.deserialize_struct(
+      "Individual",
+      INDIVIDUAL_FIELDS,
+      IndividualVisitor(PhantomData),
+    )
+  }
+}
+
+struct IndividualVisitor<'input>(PhantomData<Individual<'input>>);
+
+impl<'de: 'input, 'input> Visitor<'de> for IndividualVisitor<'input> {
+  type Value = Individual<'input>;
+
+  fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+    formatter.write_str("an INDI record")
+  }
+
+  fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+  where
+    A: MapAccess<'de>,
+  {
+    let mut xref_id = None;
+    let mut name = vec![];
+    let mut sex = None;
+    let mut events = vec![];
+    let mut attributes = vec![];
+    let mut child_to_family_links = vec![];
+    let mut spouse_to_family_links = vec![];
+    let mut sources = vec![];
+    let mut multimedia_links = vec![];
+    let mut notes = vec![];
+    let mut uid = None;
+    let mut change_date = None;
+
+    while let Some(key) = map.next_key::<&str>()? {
+      match key {
+        "XREF_ID" => xref_id = Some(map.next_value()?),
+        "NAME" => name.extend(map.next_value::<Vec<_>>()?),
+        "SEX" => sex = Some(map.next_value()?),
+        "FAMC" => child_to_family_links.extend(map.next_value::<Vec<_>>()?),
+        "FAMS" => spouse_to_family_links.extend(map.next_value::<Vec<_>>()?),
+        "SOUR" => sources.extend(map.next_value::<Vec<_>>()?),
+        "OBJE" => multimedia_links.extend(map.next_value::<Vec<_>>()?),
+        "NOTE" => notes.extend(map.next_value::<Vec<_>>()?),
+        "_UID" => uid = Some(map.next_value()?),
+        "CHAN" => change_date = Some(map.next_value()?),
+        tag if IndividualEvent::TAGS.contains(&tag) => events.push(map.next_value()?),
+        tag if IndividualAttribute::TAGS.contains(&tag) => attributes.push(map.next_value()?),
+        _ => {
+          map.next_value::<IgnoredAny>()?;
+        }
+      }
+    }
+
+    Ok(Individual {
+      xref_id: xref_id.ok_or_else(|| de::Error::missing_field("XREF_ID"))?,
+      name,
+      sex,
+      events,
+      attributes,
+      child_to_family_links,
+      spouse_to_family_links,
+      sources,
+      multimedia_links,
+      notes,
+      uid,
+      change_date,
+    })
+  }
 }
 
 pub fn deserialize_boolean<'de, D>(deserializer: D) -> Result<bool, D::Error>
@@ -43,7 +136,7 @@ where
   }
 }
 
-#[derive(Copy, Clone, Debug, Deserialize, PartialEq)]
+#[derive(Copy, Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub enum SexValue {
   #[serde(rename = "M")]
   Male,
@@ -53,26 +146,22 @@ pub enum SexValue {
   Undetermined,
 }
 
-#[derive(Debug, Deserialize, PartialEq)]
-pub struct BirthEvent<'input> {
-  #[serde(rename = "_PRIM", deserialize_with = "deserialize_boolean")]
-  pub is_primary: bool,
-  #[serde(rename = "DATE", deserialize_with = "deserialise_date_greg")]
-  pub date: DateGreg,
-  #[serde(rename = "PLAC")]
-  pub place: Option<&'input str>,
-}
-
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
 pub struct ChangeDate {
   #[serde(rename = "DATE")]
   pub date_time: ChangeDateDateTime,
 }
 
-#[derive(Debug, Deserialize, PartialEq)]
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
 pub struct ChangeDateDateTime {
   #[serde(rename = "DATE", deserialize_with = "deserialise_date_exact")]
   pub date: DateExact,
   #[serde(rename = "TIME", deserialize_with = "deserialise_time_value")]
   pub time: TimeValue,
 }
+
+impl ChangeDateDateTime {
+  pub fn to_naive_date_time(&self) -> Option<NaiveDateTime> {
+    self.date.to_naive_date_time(&self.time)
+  }
+}
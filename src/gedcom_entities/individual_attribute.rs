@@ -0,0 +1,29 @@
+use super::event_detail::event_detail_enum;
+
+event_detail_enum! {
+  /// A characteristic of an individual, such as their occupation. The attribute's value (_e.g._
+  /// `Weaver` in `1 OCCU Weaver`) is the value of its detail, and the detail says when and where it
+  /// applied.
+  pub enum IndividualAttribute {
+    /// Caste name.
+    Caste = "CAST",
+    PhysicalDescription = "DSCR",
+    /// Scholastic achievement.
+    Education = "EDUC",
+    NationalIdNumber = "IDNO",
+    /// Nationality or tribal origin.
+    Nationality = "NATI",
+    NumberOfChildren = "NCHI",
+    NumberOfMarriages = "NMR",
+    Occupation = "OCCU",
+    Possessions = "PROP",
+    Religion = "RELI",
+    /// Has no value, only the detail of where and when.
+    Residence = "RESI",
+    SocialSecurityNumber = "SSN",
+    /// Nobility type title.
+    Title = "TITL",
+    /// A generic fact, described by its `TYPE`.
+    Fact = "FACT",
+  }
+}
@@ -0,0 +1,30 @@
+use super::event_detail::event_detail_enum;
+
+event_detail_enum! {
+  /// An event in the life of an individual. A generic `EVEN` is described by its `TYPE`.
+  pub enum IndividualEvent {
+    Birth = "BIRT",
+    Christening = "CHR",
+    Death = "DEAT",
+    Burial = "BURI",
+    Cremation = "CREM",
+    Adoption = "ADOP",
+    Baptism = "BAPM",
+    BarMitzvah = "BARM",
+    BasMitzvah = "BASM",
+    Blessing = "BLES",
+    AdultChristening = "CHRA",
+    Confirmation = "CONF",
+    FirstCommunion = "FCOM",
+    Ordination = "ORDN",
+    Naturalization = "NATU",
+    Emigration = "EMIG",
+    Immigration = "IMMI",
+    Census = "CENS",
+    Probate = "PROB",
+    Will = "WILL",
+    Graduation = "GRAD",
+    Retirement = "RETI",
+    Event = "EVEN",
+  }
+}
@@ -0,0 +1,411 @@
+//! Conversion of dates in every calendar to a Julian Day Number, the number of days since noon on
+//! 1 January 4713 B.C. in the Julian calendar, so that they can be compared with each other.
+//!
+//! Only complete dates have a Julian Day Number, but every date has bounds: the first and last
+//! days of its month or year.
+
+use super::{Date, DateBounds, DateFren, DateGreg, DateHebr, DateJuln, FrenchMonth, HebrewMonth};
+
+impl Date {
+  pub fn julian_day_number(&self) -> Option<i32> {
+    match self {
+      Date::Gregorian(date) => date.julian_day_number(),
+      Date::Julian(date) => date.julian_day_number(),
+      Date::Hebrew(date) => date.julian_day_number(),
+      Date::French(date) => date.julian_day_number(),
+    }
+  }
+
+  pub fn bounds(&self) -> DateBounds {
+    match self {
+      Date::Gregorian(date) => date.bounds(),
+      Date::Julian(date) => date.bounds(),
+      Date::Hebrew(date) => date.bounds(),
+      Date::French(date) => date.bounds(),
+    }
+  }
+}
+
+impl DateGreg {
+  pub fn julian_day_number(&self) -> Option<i32> {
+    let (day, month) = (self.day?, self.month?);
+    Some(gregorian_to_julian_day_number(
+      astronomical_year(self.new_style_year()),
+      month.number() as i32,
+      day.into(),
+    ))
+  }
+
+  pub fn bounds(&self) -> DateBounds {
+    month_calendar_bounds(
+      astronomical_year(self.new_style_year()),
+      self.month.map(|month| month.number() as i32),
+      self.day.map(i32::from),
+      gregorian_to_julian_day_number,
+    )
+  }
+}
+
+impl DateJuln {
+  pub fn julian_day_number(&self) -> Option<i32> {
+    let (day, month) = (self.day?, self.month?);
+    Some(julian_to_julian_day_number(
+      astronomical_year(self.year),
+      month.number() as i32,
+      day.into(),
+    ))
+  }
+
+  pub fn bounds(&self) -> DateBounds {
+    month_calendar_bounds(
+      astronomical_year(self.year),
+      self.month.map(|month| month.number() as i32),
+      self.day.map(i32::from),
+      julian_to_julian_day_number,
+    )
+  }
+}
+
+impl DateHebr {
+  pub fn julian_day_number(&self) -> Option<i32> {
+    let (day, month) = (self.day?, self.month?);
+    Some(hebrew_to_julian_day_number(
+      self.year.into(),
+      month,
+      day.into(),
+    ))
+  }
+
+  pub fn bounds(&self) -> DateBounds {
+    let year = self.year.into();
+    match (self.day, self.month) {
+      (Some(day), Some(month)) => {
+        DateBounds::day(hebrew_to_julian_day_number(year, month, day.into()))
+      }
+      (None, Some(month)) => {
+        let first_day = hebrew_to_julian_day_number(year, month, 1);
+        DateBounds::between(first_day, first_day + hebrew_month_length(year, month) - 1)
+      }
+      _ => DateBounds::between(hebrew_new_year(year), hebrew_new_year(year + 1) - 1),
+    }
+  }
+}
+
+impl DateFren {
+  pub fn julian_day_number(&self) -> Option<i32> {
+    let (day, month) = (self.day?, self.month?);
+    Some(french_to_julian_day_number(
+      self.year.into(),
+      month,
+      day.into(),
+    ))
+  }
+
+  pub fn bounds(&self) -> DateBounds {
+    let year = self.year.into();
+    let next_year = french_to_julian_day_number(year + 1, FrenchMonth::Vendemiaire, 1);
+    match (self.day, self.month) {
+      (Some(day), Some(month)) => {
+        DateBounds::day(french_to_julian_day_number(year, month, day.into()))
+      }
+      // The complementary days run up to the next year, whether there are five or six of them.
+      (None, Some(FrenchMonth::JoursComplementaires)) => DateBounds::between(
+        french_to_julian_day_number(year, FrenchMonth::JoursComplementaires, 1),
+        next_year - 1,
+      ),
+      (None, Some(month)) => {
+        let first_day = french_to_julian_day_number(year, month, 1);
+        DateBounds::between(first_day, first_day + 29)
+      }
+      _ => DateBounds::between(
+        french_to_julian_day_number(year, FrenchMonth::Vendemiaire, 1),
+        next_year - 1,
+      ),
+    }
+  }
+}
+
+/// The bounds of a date in a calendar of twelve numbered months, where the last day of a month is
+/// the day before the first day of the next.
+fn month_calendar_bounds(
+  year: i32,
+  month: Option<i32>,
+  day: Option<i32>,
+  to_julian_day_number: fn(i32, i32, i32) -> i32,
+) -> DateBounds {
+  match (day, month) {
+    (Some(day), Some(month)) => DateBounds::day(to_julian_day_number(year, month, day)),
+    (None, Some(12)) => DateBounds::between(
+      to_julian_day_number(year, 12, 1),
+      to_julian_day_number(year + 1, 1, 1) - 1,
+    ),
+    (None, Some(month)) => DateBounds::between(
+      to_julian_day_number(year, month, 1),
+      to_julian_day_number(year, month + 1, 1) - 1,
+    ),
+    _ => DateBounds::between(
+      to_julian_day_number(year, 1, 1),
+      to_julian_day_number(year + 1, 1, 1) - 1,
+    ),
+  }
+}
+
+/// Astronomical year numbering has a year 0, which is 1 B.C.
+fn astronomical_year(year: i16) -> i32 {
+  if year < 0 {
+    i32::from(year) + 1
+  } else {
+    year.into()
+  }
+}
+
+// Both of these are from Fliegel and Van Flandern (1968), counting years from March so that the
+// leap day is at the end of the year.
+fn gregorian_to_julian_day_number(year: i32, month: i32, day: i32) -> i32 {
+  let a = (14 - month) / 12;
+  let y = year + 4800 - a;
+  let m = month + 12 * a - 3;
+  day + (153 * m + 2) / 5 + 365 * y + y / 4 - y / 100 + y / 400 - 32045
+}
+
+fn julian_to_julian_day_number(year: i32, month: i32, day: i32) -> i32 {
+  let a = (14 - month) / 12;
+  let y = year + 4800 - a;
+  let m = month + 12 * a - 3;
+  day + (153 * m + 2) / 5 + 365 * y + y / 4 - 32083
+}
+
+// ===============
+// Hebrew calendar
+// ===============
+
+// 1 Tishri AM 1, which was 7 October 3761 B.C. in the Julian calendar.
+const HEBREW_EPOCH: i32 = 347_998;
+
+pub(super) fn is_hebrew_leap_year(year: i32) -> bool {
+  (7 * year + 1).rem_euclid(19) < 7
+}
+
+/// Days from the epoch to the new year, from the molad (new moon) of Tishri, before the
+/// postponements that depend on the lengths of the neighbouring years.
+fn hebrew_calendar_elapsed_days(year: i32) -> i32 {
+  let months_elapsed = (235 * year - 234).div_euclid(19);
+  let parts_elapsed = 12_084 + 13_753 * i64::from(months_elapsed);
+  let day = 29 * months_elapsed + (parts_elapsed / 25_920) as i32;
+  if (3 * (day + 1)).rem_euclid(7) < 3 {
+    day + 1
+  } else {
+    day
+  }
+}
+
+fn hebrew_new_year_delay(year: i32) -> i32 {
+  let last_year = hebrew_calendar_elapsed_days(year - 1);
+  let this_year = hebrew_calendar_elapsed_days(year);
+  let next_year = hebrew_calendar_elapsed_days(year + 1);
+  if next_year - this_year == 356 {
+    2
+  } else if this_year - last_year == 382 {
+    1
+  } else {
+    0
+  }
+}
+
+fn hebrew_new_year(year: i32) -> i32 {
+  HEBREW_EPOCH + hebrew_calendar_elapsed_days(year) + hebrew_new_year_delay(year)
+}
+
+fn hebrew_year_length(year: i32) -> i32 {
+  hebrew_new_year(year + 1) - hebrew_new_year(year)
+}
+
+pub(super) fn hebrew_month_length(year: i32, month: HebrewMonth) -> i32 {
+  use HebrewMonth::*;
+  match month {
+    Tishri | Shevat | Nisan | Sivan | Av => 30,
+    Tevet | AdarSheni | Iyar | Tammuz | Elul => 29,
+    // Cheshvan is long and Kislev is short in "complete" and "deficient" years respectively.
+    Cheshvan if hebrew_year_length(year) % 10 == 5 => 30,
+    Cheshvan => 29,
+    Kislev if hebrew_year_length(year) % 10 == 3 => 29,
+    Kislev => 30,
+    // In leap years, ADR is Adar I, which has 30 days.
+    Adar if is_hebrew_leap_year(year) => 30,
+    Adar => 29,
+  }
+}
+
+const HEBREW_MONTHS: [HebrewMonth; 13] = [
+  HebrewMonth::Tishri,
+  HebrewMonth::Cheshvan,
+  HebrewMonth::Kislev,
+  HebrewMonth::Tevet,
+  HebrewMonth::Shevat,
+  HebrewMonth::Adar,
+  HebrewMonth::AdarSheni,
+  HebrewMonth::Nisan,
+  HebrewMonth::Iyar,
+  HebrewMonth::Sivan,
+  HebrewMonth::Tammuz,
+  HebrewMonth::Av,
+  HebrewMonth::Elul,
+];
+
+fn hebrew_to_julian_day_number(year: i32, month: HebrewMonth, day: i32) -> i32 {
+  let days_before_month: i32 = HEBREW_MONTHS
+    .iter()
+    .take_while(|&&other_month| other_month != month)
+    .filter(|&&other_month| other_month != HebrewMonth::AdarSheni || is_hebrew_leap_year(year))
+    .map(|&other_month| hebrew_month_length(year, other_month))
+    .sum();
+  hebrew_new_year(year) + days_before_month + day - 1
+}
+
+// ==========================
+// French Republican calendar
+// ==========================
+
+// 1 Vendémiaire An I, which was 22 September 1792 in the Gregorian calendar.
+const FRENCH_EPOCH: i32 = 2_375_840;
+
+// Years 3, 7 and 11 were leap years while the calendar was in use, and later years follow the same
+// four-yearly pattern, so the leap day (the sixth complementary day) falls before the next year.
+/// Every month has 30 days, and the complementary days make up the rest of the year.
+pub(super) fn french_month_length(year: i32, month: FrenchMonth) -> i32 {
+  match month {
+    FrenchMonth::JoursComplementaires if (year + 1).rem_euclid(4) == 0 => 6,
+    FrenchMonth::JoursComplementaires => 5,
+    _ => 30,
+  }
+}
+
+fn french_to_julian_day_number(year: i32, month: FrenchMonth, day: i32) -> i32 {
+  let month_index = month as i32;
+  FRENCH_EPOCH + 365 * (year - 1) + year.div_euclid(4) + 30 * month_index + day - 1
+}
+
+#[test]
+fn gregorian_julian_day_number() {
+  use super::Month;
+
+  let date = DateGreg {
+    day: Some(1),
+    month: Some(Month::January),
+    year: 2000,
+    dual_year: None,
+  };
+  assert_eq!(date.julian_day_number(), Some(2_451_545));
+}
+
+#[test]
+fn julian_julian_day_number() {
+  use super::Month;
+
+  // The day before Great Britain switched to the Gregorian calendar, on 14 SEP 1752.
+  let date = DateJuln {
+    day: Some(2),
+    month: Some(Month::September),
+    year: 1752,
+  };
+  let next_day = DateGreg {
+    day: Some(14),
+    month: Some(Month::September),
+    year: 1752,
+    dual_year: None,
+  };
+  assert_eq!(date.julian_day_number(), Some(2_361_221));
+  assert_eq!(next_day.julian_day_number(), Some(2_361_222));
+}
+
+#[test]
+fn dual_dated_julian_day_number() {
+  use super::Month;
+
+  // 1731/32 is counted from 1 January 1732, and 44 B.C. is 1 B.C. plus 43 years earlier.
+  let dual_dated = DateGreg {
+    day: Some(11),
+    month: Some(Month::February),
+    year: 1731,
+    dual_year: Some(1732),
+  };
+  let before_common_era = DateJuln {
+    day: Some(15),
+    month: Some(Month::March),
+    year: -44,
+  };
+  assert_eq!(dual_dated.julian_day_number(), Some(2_353_701));
+  assert_eq!(before_common_era.julian_day_number(), Some(1_705_426));
+}
+
+#[test]
+fn hebrew_julian_day_number() {
+  use super::Month;
+
+  // Rosh Hashanah 5780 and Passover 5779.
+  let new_year = DateHebr {
+    day: Some(1),
+    month: Some(HebrewMonth::Tishri),
+    year: 5780,
+  };
+  let passover = DateHebr {
+    day: Some(15),
+    month: Some(HebrewMonth::Nisan),
+    year: 5779,
+  };
+  assert_eq!(
+    new_year.julian_day_number(),
+    DateGreg {
+      day: Some(30),
+      month: Some(Month::September),
+      year: 2019,
+      dual_year: None
+    }
+    .julian_day_number()
+  );
+  assert_eq!(
+    passover.julian_day_number(),
+    DateGreg {
+      day: Some(20),
+      month: Some(Month::April),
+      year: 2019,
+      dual_year: None
+    }
+    .julian_day_number()
+  );
+}
+
+#[test]
+fn french_julian_day_number() {
+  use super::Month;
+
+  // 18 Brumaire An VIII, Napoleon's coup d'état.
+  let date = DateFren {
+    day: Some(18),
+    month: Some(FrenchMonth::Brumaire),
+    year: 8,
+  };
+  assert_eq!(
+    date.julian_day_number(),
+    DateGreg {
+      day: Some(9),
+      month: Some(Month::November),
+      year: 1799,
+      dual_year: None
+    }
+    .julian_day_number()
+  );
+}
+
+#[test]
+fn partial_date_has_no_julian_day_number() {
+  use super::Month;
+
+  let date = DateGreg {
+    day: None,
+    month: Some(Month::June),
+    year: 1890,
+    dual_year: None,
+  };
+  assert_eq!(date.julian_day_number(), None);
+}
@@ -1,13 +1,46 @@
+mod address;
+mod date_comparison;
+mod date_time_error;
 mod dates;
+mod event_detail;
+mod family;
+mod family_event;
 mod header;
 mod individual;
+mod individual_attribute;
+mod individual_event;
+mod julian_day_number;
+mod multimedia;
+mod note;
+mod personal_name;
+mod place;
 mod record;
+mod repository_record;
+mod source_citation;
+mod source_record;
 mod submission_record;
+mod submitter_record;
 mod time_value;
 
+pub use address::Address;
+pub use date_comparison::{DateBounds, DateComparison};
+pub use date_time_error::DateTimeError;
 pub use dates::*;
+pub use event_detail::EventDetail;
+pub use family::*;
+pub use family_event::FamilyEvent;
 pub use header::*;
 pub use individual::*;
+pub use individual_attribute::IndividualAttribute;
+pub use individual_event::IndividualEvent;
+pub use multimedia::*;
+pub use note::*;
+pub use personal_name::*;
+pub use place::*;
 pub use record::Record;
-pub use submission_record::SubmissionRecord;
+pub use repository_record::*;
+pub use source_citation::*;
+pub use source_record::*;
+pub use submission_record::*;
+pub use submitter_record::SubmitterRecord;
 pub use time_value::*;
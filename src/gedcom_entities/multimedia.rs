@@ -0,0 +1,76 @@
+use super::{deserialize_boolean, ChangeDate, Note, SourceCitation};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+/// A photo, scan or other file, which multimedia links can point to.
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+pub struct MultimediaRecord<'input> {
+  #[serde(rename = "XREF_ID")]
+  pub xref_id: &'input str,
+  #[serde(borrow, rename = "FILE", default)]
+  pub files: Vec<MultimediaFile<'input>>,
+  #[serde(borrow, rename = "NOTE", default)]
+  pub notes: Vec<Note<'input>>,
+  #[serde(borrow, rename = "SOUR", default)]
+  pub sources: Vec<SourceCitation<'input>>,
+  #[serde(rename = "CHAN")]
+  pub change_date: Option<ChangeDate>,
+}
+
+/// A link to multimedia, either a pointer to a multimedia record (_e.g._ `@O1@`) or a
+/// description of the files.
+#[derive(Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct MultimediaLink<'input> {
+  #[serde(rename = "OBJE")]
+  pub object: Option<&'input str>,
+  #[serde(borrow, rename = "FILE", default)]
+  pub files: Vec<MultimediaFile<'input>>,
+  #[serde(borrow, rename = "TITL")]
+  pub title: Option<Cow<'input, str>>,
+  #[serde(
+    rename = "_PRIM",
+    default,
+    deserialize_with = "deserialize_boolean",
+    skip_serializing_if = "std::ops::Not::not"
+  )]
+  pub is_primary: bool,
+  #[serde(rename = "_CROP")]
+  pub crop: Option<Crop>,
+}
+
+#[derive(Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct MultimediaFile<'input> {
+  /// The path or URL of the file.
+  #[serde(rename = "FILE")]
+  pub file: &'input str,
+  #[serde(borrow, rename = "FORM")]
+  pub format: Option<MultimediaFormat<'input>>,
+  #[serde(borrow, rename = "TITL")]
+  pub title: Option<Cow<'input, str>>,
+}
+
+/// The file format, such as `jpg`, and what kind of material the file shows.
+#[derive(Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct MultimediaFormat<'input> {
+  #[serde(rename = "FORM")]
+  pub format: &'input str,
+  /// The kind of material, such as `photo` or `document`, in a multimedia record.
+  #[serde(rename = "TYPE")]
+  pub media_type: Option<&'input str>,
+  /// The kind of material, such as `photo` or `document`, in a multimedia link.
+  #[serde(rename = "MEDI")]
+  pub media: Option<&'input str>,
+}
+
+/// The part of an image to show, in pixels.
+#[derive(Copy, Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Crop {
+  #[serde(rename = "_LEFT")]
+  pub left: u32,
+  #[serde(rename = "_TOP")]
+  pub top: u32,
+  #[serde(rename = "_WIDTH")]
+  pub width: u32,
+  #[serde(rename = "_HEIGHT")]
+  pub height: u32,
+}
@@ -0,0 +1,63 @@
+use super::{record::xref_pointer, ChangeDate, Record, SourceCitation};
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, collections::HashMap};
+
+/// A note shared by several records, which notes can point to.
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+pub struct NoteRecord<'input> {
+  #[serde(rename = "XREF_ID")]
+  pub xref_id: &'input str,
+  #[serde(borrow, rename = "NOTE", default)]
+  pub text: Cow<'input, str>,
+  #[serde(borrow, rename = "SOUR", default)]
+  pub sources: Vec<SourceCitation<'input>>,
+  #[serde(rename = "CHAN")]
+  pub change_date: Option<ChangeDate>,
+}
+
+/// A note, either a pointer to a note record (_e.g._ `@N1@`) or the text of the note.
+#[derive(Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Note<'input> {
+  #[serde(borrow, rename = "NOTE", default)]
+  pub text: Cow<'input, str>,
+  #[serde(borrow, rename = "SOUR", default)]
+  pub sources: Vec<SourceCitation<'input>>,
+}
+
+impl Note<'_> {
+  /// The cross-reference ID of the note record, if the note points to one.
+  pub fn note_xref_id(&self) -> Option<&str> {
+    xref_pointer(&self.text)
+  }
+}
+
+/// Looks up the text of notes that point to note records.
+pub struct NoteResolver<'a, 'input> {
+  note_records: HashMap<&'input str, &'a NoteRecord<'input>>,
+}
+
+impl<'a, 'input> NoteResolver<'a, 'input> {
+  pub fn new(records: &'a [Record<'input>]) -> Self {
+    Self {
+      note_records: records
+        .iter()
+        .filter_map(|record| match record {
+          Record::Note(note_record) => Some((note_record.xref_id, note_record)),
+          _ => None,
+        })
+        .collect(),
+    }
+  }
+
+  /// The text of the note record the note points to, or the note's own text if it does not point
+  /// to one. Returns `None` if the pointer does not resolve to a note record.
+  pub fn resolve<'b>(&'b self, note: &'b Note) -> Option<&'b str> {
+    match note.note_xref_id() {
+      Some(xref_id) => self
+        .note_records
+        .get(xref_id)
+        .map(|note_record| note_record.text.as_ref()),
+      None => Some(&note.text),
+    }
+  }
+}
@@ -0,0 +1,315 @@
+use super::{deserialize_boolean, Note, SourceCitation};
+use crate::parsers::parse_name_value;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::{
+  borrow::Cow,
+  fmt::{Display, Formatter},
+};
+
+#[derive(Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct PersonalName<'input> {
+  #[serde(
+    borrow,
+    rename = "NAME",
+    default,
+    deserialize_with = "deserialise_optional_name_value"
+  )]
+  pub value: Option<NameValue<'input>>,
+  #[serde(rename = "NPFX")]
+  pub prefix: Option<&'input str>,
+  #[serde(rename = "GIVN")]
+  pub given_name: Option<&'input str>,
+  #[serde(rename = "NICK")]
+  pub nickname: Option<&'input str>,
+  #[serde(rename = "SPFX")]
+  pub surname_prefix: Option<&'input str>,
+  #[serde(rename = "SURN")]
+  pub surname: Option<&'input str>,
+  #[serde(rename = "NSFX")]
+  pub suffix: Option<&'input str>,
+  #[serde(rename = "TYPE")]
+  pub name_type: Option<NameType<'input>>,
+  #[serde(borrow, rename = "FONE", default)]
+  pub phonetic_variations: Vec<NameVariation<'input>>,
+  #[serde(borrow, rename = "ROMN", default)]
+  pub romanized_variations: Vec<NameVariation<'input>>,
+  #[serde(borrow, rename = "NOTE", default)]
+  pub notes: Vec<Note<'input>>,
+  #[serde(borrow, rename = "SOUR", default)]
+  pub sources: Vec<SourceCitation<'input>>,
+  #[serde(
+    rename = "_PRIM",
+    default,
+    deserialize_with = "deserialize_boolean",
+    skip_serializing_if = "std::ops::Not::not"
+  )]
+  pub is_primary: bool,
+}
+
+impl<'input> PersonalName<'input> {
+  /// The `GIVN` piece, or else the given names from the name value.
+  pub fn given_names(&self) -> Option<&str> {
+    self
+      .given_name
+      .or_else(|| self.value.as_ref().and_then(|value| value.given.as_deref()))
+  }
+
+  /// The `SURN` piece, or else the surname from between the slashes of the name value.
+  pub fn surnames(&self) -> Option<&str> {
+    self.surname.or_else(|| {
+      self
+        .value
+        .as_ref()
+        .and_then(|value| value.surname.as_deref())
+    })
+  }
+}
+
+/// A phonetic (`FONE`) or romanized (`ROMN`) variation of a name, such as its kana or pinyin
+/// spelling.
+#[derive(Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct NameVariation<'input> {
+  #[serde(
+    borrow,
+    rename = "LINE_VALUE",
+    default,
+    deserialize_with = "deserialise_optional_name_value"
+  )]
+  pub value: Option<NameValue<'input>>,
+  /// How the variation was made, such as `hangul` or `kana` for phonetic variations and `pinyin`,
+  /// `romaji` or `wadegiles` for romanized ones.
+  #[serde(rename = "TYPE")]
+  pub variation_type: Option<&'input str>,
+  #[serde(rename = "NPFX")]
+  pub prefix: Option<&'input str>,
+  #[serde(rename = "GIVN")]
+  pub given_name: Option<&'input str>,
+  #[serde(rename = "NICK")]
+  pub nickname: Option<&'input str>,
+  #[serde(rename = "SPFX")]
+  pub surname_prefix: Option<&'input str>,
+  #[serde(rename = "SURN")]
+  pub surname: Option<&'input str>,
+  #[serde(rename = "NSFX")]
+  pub suffix: Option<&'input str>,
+}
+
+/// The value of a `NAME` line, such as `Gavin /Henderson/ Jr.`, in which the surname is between
+/// slashes.
+#[derive(Debug, PartialEq)]
+pub struct NameValue<'input> {
+  pub given: Option<Cow<'input, str>>,
+  /// An empty surname (`//`) means that the surname is not known.
+  pub surname: Option<Cow<'input, str>>,
+  pub suffix: Option<Cow<'input, str>>,
+}
+
+impl NameValue<'_> {
+  /// Copies the pieces of the name, for a name read from a value that was continued with CONT or
+  /// CONC lines.
+  pub fn into_owned(self) -> NameValue<'static> {
+    NameValue {
+      given: self.given.map(|given| Cow::Owned(given.into_owned())),
+      surname: self.surname.map(|surname| Cow::Owned(surname.into_owned())),
+      suffix: self.suffix.map(|suffix| Cow::Owned(suffix.into_owned())),
+    }
+  }
+}
+
+impl Display for NameValue<'_> {
+  fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+    let mut separator = "";
+    if let Some(given) = &self.given {
+      write!(formatter, "{}", given)?;
+      separator = " ";
+    }
+    if let Some(surname) = &self.surname {
+      write!(formatter, "{}/{}/", separator, surname)?;
+      separator = " ";
+    }
+    if let Some(suffix) = &self.suffix {
+      write!(formatter, "{}{}", separator, suffix)?;
+    }
+    Ok(())
+  }
+}
+
+impl Serialize for NameValue<'_> {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: Serializer,
+  {
+    serializer.collect_str(self)
+  }
+}
+
+pub fn deserialise_optional_name_value<'de: 'input, 'input, D>(
+  deserializer: D,
+) -> Result<Option<NameValue<'input>>, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  let LineValue(name_as_string) = LineValue::deserialize(deserializer)?;
+  let name = match name_as_string {
+    Cow::Borrowed(name_as_string) => {
+      parse_name_value(name_as_string)
+        .map_err(serde::de::Error::custom)?
+        .1
+    }
+    Cow::Owned(name_as_string) => parse_name_value(&name_as_string)
+      .map_err(serde::de::Error::custom)?
+      .1
+      .into_owned(),
+  };
+  Ok(Some(name))
+}
+
+/// A line value, borrowed from the input unless it was continued with CONT or CONC lines.
+#[derive(Deserialize)]
+#[serde(transparent)]
+struct LineValue<'input>(#[serde(borrow)] Cow<'input, str>);
+
+/// The kind of name, such as a married name. Any other type is user defined.
+#[derive(Debug, PartialEq)]
+pub enum NameType<'input> {
+  AlsoKnownAs,
+  Birth,
+  Immigrant,
+  Maiden,
+  Married,
+  UserDefined(&'input str),
+}
+
+impl Display for NameType<'_> {
+  fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+    formatter.write_str(match self {
+      NameType::AlsoKnownAs => "aka",
+      NameType::Birth => "birth",
+      NameType::Immigrant => "immigrant",
+      NameType::Maiden => "maiden",
+      NameType::Married => "married",
+      NameType::UserDefined(name_type) => name_type,
+    })
+  }
+}
+
+impl Serialize for NameType<'_> {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: Serializer,
+  {
+    serializer.collect_str(self)
+  }
+}
+
+impl<'de: 'input, 'input> Deserialize<'de> for NameType<'input> {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    // Types are matched whatever their case, as files write `birth` or `BIRTH`.
+    let name_type = <&str>::deserialize(deserializer)?;
+    Ok(if name_type.eq_ignore_ascii_case("aka") {
+      NameType::AlsoKnownAs
+    } else if name_type.eq_ignore_ascii_case("birth") {
+      NameType::Birth
+    } else if name_type.eq_ignore_ascii_case("immigrant") {
+      NameType::Immigrant
+    } else if name_type.eq_ignore_ascii_case("maiden") {
+      NameType::Maiden
+    } else if name_type.eq_ignore_ascii_case("married") {
+      NameType::Married
+    } else {
+      NameType::UserDefined(name_type)
+    })
+  }
+}
+
+#[test]
+fn personal_name_from_name_value() {
+  use crate::gedcom_data_format::from_str;
+
+  // Arrange
+  let input = "1 NAME Gavin /Henderson/\n";
+
+  // Act
+  let name: PersonalName = from_str(input).expect("No errors during this test");
+
+  // Assert
+  assert_eq!(name.given_names(), Some("Gavin"));
+  assert_eq!(name.surnames(), Some("Henderson"));
+  assert!(!name.is_primary);
+}
+
+#[test]
+fn personal_name_pieces_and_variations() {
+  use crate::gedcom_data_format::from_str;
+
+  // Arrange
+  let input = "1 NAME Lt. Cmndr. Joseph /Allen/ jr.
+2 TYPE married
+2 NPFX Lt. Cmndr.
+2 GIVN Joseph
+2 NICK Joe
+2 SURN Allen
+2 NSFX jr.
+2 ROMN Yosefu /Aren/
+3 TYPE romaji
+";
+
+  // Act
+  let name: PersonalName = from_str(input).expect("No errors during this test");
+
+  // Assert
+  assert_eq!(
+    name,
+    PersonalName {
+      value: Some(NameValue {
+        given: Some("Lt. Cmndr. Joseph".into()),
+        surname: Some("Allen".into()),
+        suffix: Some("jr.".into())
+      }),
+      prefix: Some("Lt. Cmndr."),
+      given_name: Some("Joseph"),
+      nickname: Some("Joe"),
+      surname: Some("Allen"),
+      suffix: Some("jr."),
+      name_type: Some(NameType::Married),
+      romanized_variations: vec![NameVariation {
+        value: Some(NameValue {
+          given: Some("Yosefu".into()),
+          surname: Some("Aren".into()),
+          suffix: None
+        }),
+        variation_type: Some("romaji"),
+        ..Default::default()
+      }],
+      ..Default::default()
+    }
+  );
+}
+
+#[test]
+fn name_types_in_any_case() {
+  use crate::gedcom_data_format::from_str;
+
+  // Arrange
+  let inputs = [
+    "1 NAME /Allen/\n2 TYPE BIRTH\n",
+    "1 NAME /Allen/\n2 TYPE Married\n",
+    "1 NAME /Allen/\n2 TYPE Stage\n",
+  ];
+  let expected_types = [
+    NameType::Birth,
+    NameType::Married,
+    NameType::UserDefined("Stage"),
+  ];
+
+  for (input, expected_type) in inputs.iter().zip(expected_types.iter()) {
+    // Act
+    let name: PersonalName = from_str(input).expect("No errors during this test");
+
+    // Assert
+    assert_eq!(name.name_type.as_ref(), Some(expected_type));
+  }
+}
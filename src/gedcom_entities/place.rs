@@ -0,0 +1,154 @@
+use super::Note;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::borrow::Cow;
+
+/// A place, named from the smallest jurisdiction to the largest (_e.g._ `Dundee, Angus,
+/// Scotland`).
+#[derive(Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Place<'input> {
+  /// Empty if the place is only given by its coordinates, as in `2 PLAC` followed by `3 MAP`.
+  #[serde(borrow, rename = "PLAC", default)]
+  pub name: Cow<'input, str>,
+  /// The kind of each jurisdiction in the name (_e.g._ `City, County, Country`), overriding the
+  /// one in the header.
+  #[serde(rename = "FORM")]
+  pub form: Option<&'input str>,
+  #[serde(borrow, rename = "FONE", default)]
+  pub phonetic_variations: Vec<PlaceVariation<'input>>,
+  #[serde(borrow, rename = "ROMN", default)]
+  pub romanized_variations: Vec<PlaceVariation<'input>>,
+  #[serde(rename = "MAP")]
+  pub map: Option<Map>,
+  #[serde(borrow, rename = "NOTE", default)]
+  pub notes: Vec<Note<'input>>,
+}
+
+impl Place<'_> {
+  /// The jurisdictions in the name, from the smallest to the largest, each with its kind if the
+  /// place or the header (`HEAD.PLAC.FORM`) says what the jurisdictions are. Jurisdictions left
+  /// out of the name are empty, so they still line up with the form.
+  pub fn jurisdictions<'a>(&'a self, header_form: Option<&'a str>) -> Vec<Jurisdiction<'a>> {
+    let mut levels = self
+      .form
+      .or(header_form)
+      .map(split_jurisdictions)
+      .unwrap_or_default()
+      .into_iter();
+    split_jurisdictions(&self.name)
+      .into_iter()
+      .map(|name| Jurisdiction {
+        name,
+        level: levels.next(),
+      })
+      .collect()
+  }
+}
+
+fn split_jurisdictions(value: &str) -> Vec<&str> {
+  value.split(',').map(str::trim).collect()
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Jurisdiction<'a> {
+  pub name: &'a str,
+  /// The kind of jurisdiction, such as `County`.
+  pub level: Option<&'a str>,
+}
+
+/// A phonetic or romanized spelling of a place name.
+#[derive(Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct PlaceVariation<'input> {
+  #[serde(borrow, rename = "LINE_VALUE")]
+  pub name: Cow<'input, str>,
+  /// How the variation was made, such as `hangul` for phonetic variations or `pinyin` for
+  /// romanized ones.
+  #[serde(rename = "TYPE")]
+  pub variation_type: Option<&'input str>,
+}
+
+/// The coordinates of a place, in decimal degrees. North and east are positive.
+#[derive(Copy, Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Map {
+  #[serde(
+    rename = "LATI",
+    deserialize_with = "deserialise_latitude",
+    serialize_with = "serialise_latitude"
+  )]
+  pub latitude: f64,
+  #[serde(
+    rename = "LONG",
+    deserialize_with = "deserialise_longitude",
+    serialize_with = "serialise_longitude"
+  )]
+  pub longitude: f64,
+}
+
+/// Gedcom writes coordinates with a compass point instead of a sign (_e.g._ `N18.150944`).
+fn deserialise_coordinate<'de, D>(
+  deserializer: D,
+  positive: char,
+  negative: char,
+) -> Result<f64, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  let coordinate = <&str>::deserialize(deserializer)?;
+  let sign = match coordinate.chars().next() {
+    Some(point) if point == positive => 1.0,
+    Some(point) if point == negative => -1.0,
+    _ => {
+      return Err(de::Error::custom(format!(
+        "Coordinate {} does not start with {} or {}",
+        coordinate, positive, negative
+      )))
+    }
+  };
+  coordinate[1..]
+    .parse::<f64>()
+    .map(|degrees| sign * degrees)
+    .map_err(|_| de::Error::custom(format!("Failed to parse coordinate {}", coordinate)))
+}
+
+fn deserialise_latitude<'de, D>(deserializer: D) -> Result<f64, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  deserialise_coordinate(deserializer, 'N', 'S')
+}
+
+fn deserialise_longitude<'de, D>(deserializer: D) -> Result<f64, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  deserialise_coordinate(deserializer, 'E', 'W')
+}
+
+fn serialise_coordinate<S>(
+  degrees: f64,
+  positive: char,
+  negative: char,
+  serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+  S: Serializer,
+{
+  if degrees < 0.0 {
+    serializer.collect_str(&format_args!("{}{}", negative, -degrees))
+  } else {
+    serializer.collect_str(&format_args!("{}{}", positive, degrees))
+  }
+}
+
+fn serialise_latitude<S>(latitude: &f64, serializer: S) -> Result<S::Ok, S::Error>
+where
+  S: Serializer,
+{
+  serialise_coordinate(*latitude, 'N', 'S', serializer)
+}
+
+fn serialise_longitude<S>(longitude: &f64, serializer: S) -> Result<S::Ok, S::Error>
+where
+  S: Serializer,
+{
+  serialise_coordinate(*longitude, 'E', 'W', serializer)
+}
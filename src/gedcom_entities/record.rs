@@ -1,14 +1,49 @@
-use super::{Header, Individual, SubmissionRecord};
-use serde::Deserialize;
+use super::{
+  Family, Header, Individual, MultimediaRecord, NoteRecord, RepositoryRecord, SourceRecord,
+  SubmissionRecord, SubmitterRecord,
+};
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Deserialize, PartialEq)]
+// Records are read once into a list, so boxing the larger ones would not save much.
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
 pub enum Record<'input> {
   #[serde(borrow, rename = "HEAD")]
   Header(Header<'input>),
   #[serde(borrow, rename = "SUBM")]
-  SubmissionRecord(SubmissionRecord<'input>),
+  Submitter(SubmitterRecord<'input>),
   #[serde(rename = "INDI")]
   Individual(Individual<'input>),
+  #[serde(rename = "FAM")]
+  Family(Family<'input>),
+  #[serde(borrow, rename = "SOUR")]
+  Source(SourceRecord<'input>),
+  #[serde(borrow, rename = "REPO")]
+  Repository(RepositoryRecord<'input>),
+  #[serde(borrow, rename = "OBJE")]
+  Multimedia(MultimediaRecord<'input>),
+  #[serde(borrow, rename = "NOTE")]
+  Note(NoteRecord<'input>),
+  #[serde(borrow, rename = "SUBN")]
+  Submission(SubmissionRecord<'input>),
   #[serde(rename = "TRLR")]
   Trailer,
 }
+
+/// The value as a cross-reference ID, if it is a pointer to a record such as `@S1@`: an ID
+/// between `@` signs, so at least three characters long.
+pub(super) fn xref_pointer(value: &str) -> Option<&str> {
+  if value.len() >= 3 && value.starts_with('@') && value.ends_with('@') {
+    Some(value)
+  } else {
+    None
+  }
+}
+
+#[test]
+fn xref_pointers() {
+  assert_eq!(xref_pointer("@S1@"), Some("@S1@"));
+  assert_eq!(xref_pointer("@"), None);
+  assert_eq!(xref_pointer("@@"), None);
+  assert_eq!(xref_pointer("Parish register"), None);
+}
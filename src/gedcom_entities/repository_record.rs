@@ -0,0 +1,48 @@
+use super::{Address, ChangeDate, Note};
+use serde::{Deserialize, Serialize};
+
+/// An archive, library or other institution that holds sources.
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+pub struct RepositoryRecord<'input> {
+  #[serde(rename = "XREF_ID")]
+  pub xref_id: &'input str,
+  #[serde(rename = "NAME")]
+  pub name: &'input str,
+  #[serde(borrow, rename = "ADDR")]
+  pub address: Option<Address<'input>>,
+  #[serde(rename = "PHON", default)]
+  pub phone_numbers: Vec<&'input str>,
+  #[serde(rename = "EMAIL", default)]
+  pub email_addresses: Vec<&'input str>,
+  #[serde(rename = "FAX", default)]
+  pub fax_numbers: Vec<&'input str>,
+  #[serde(rename = "WWW", default)]
+  pub web_pages: Vec<&'input str>,
+  #[serde(borrow, rename = "NOTE", default)]
+  pub notes: Vec<Note<'input>>,
+  #[serde(rename = "CHAN")]
+  pub change_date: Option<ChangeDate>,
+}
+
+/// A pointer from a source to the repository that holds it.
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+pub struct RepositoryCitation<'input> {
+  /// A pointer to the repository record. Without one, the citation only gives the call numbers
+  /// and notes of a repository that has no record.
+  #[serde(rename = "REPO", default)]
+  pub repository: Option<&'input str>,
+  #[serde(rename = "CALN", default)]
+  pub call_numbers: Vec<CallNumber<'input>>,
+  #[serde(borrow, rename = "NOTE", default)]
+  pub notes: Vec<Note<'input>>,
+}
+
+/// The number the repository files the source under.
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+pub struct CallNumber<'input> {
+  #[serde(rename = "CALN")]
+  pub call_number: &'input str,
+  /// The kind of material the source is held as, such as `book`, `film` or `manuscript`.
+  #[serde(rename = "MEDI")]
+  pub media: Option<&'input str>,
+}
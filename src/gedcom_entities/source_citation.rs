@@ -0,0 +1,71 @@
+use super::{
+  deserialise_optional_date_value, record::xref_pointer, DateValue, MultimediaLink, Note,
+};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+/// A citation of the source of some information, either a pointer to a source record (_e.g._
+/// `@S1@`) or, for systems without source records, a description of the source.
+#[derive(Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct SourceCitation<'input> {
+  #[serde(borrow, rename = "SOUR")]
+  pub source: Cow<'input, str>,
+  /// Where in the source the information is, such as a page or film number.
+  #[serde(borrow, rename = "PAGE")]
+  pub page: Option<Cow<'input, str>>,
+  #[serde(borrow, rename = "EVEN")]
+  pub event: Option<CitedEvent<'input>>,
+  #[serde(borrow, rename = "DATA")]
+  pub data: Option<CitationData<'input>>,
+  /// Text from the source, for citations without a source record.
+  #[serde(borrow, rename = "TEXT", default)]
+  pub text: Vec<Cow<'input, str>>,
+  #[serde(borrow, rename = "OBJE", default)]
+  pub multimedia_links: Vec<MultimediaLink<'input>>,
+  #[serde(borrow, rename = "NOTE", default)]
+  pub notes: Vec<Note<'input>>,
+  #[serde(rename = "QUAY")]
+  pub quality: Option<CertaintyAssessment>,
+}
+
+impl SourceCitation<'_> {
+  /// The cross-reference ID of the cited source record, if the citation points to one.
+  pub fn source_xref_id(&self) -> Option<&str> {
+    xref_pointer(&self.source)
+  }
+}
+
+/// The kind of event that the cited source recorded, and the role of the individual in it.
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+pub struct CitedEvent<'input> {
+  #[serde(rename = "EVEN")]
+  pub event_type: &'input str,
+  #[serde(rename = "ROLE")]
+  pub role: Option<&'input str>,
+}
+
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+pub struct CitationData<'input> {
+  /// When the information was entered into the source.
+  #[serde(
+    rename = "DATE",
+    default,
+    deserialize_with = "deserialise_optional_date_value"
+  )]
+  pub date: Option<DateValue>,
+  #[serde(borrow, rename = "TEXT", default)]
+  pub text: Vec<Cow<'input, str>>,
+}
+
+/// How reliable the cited evidence is.
+#[derive(Copy, Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub enum CertaintyAssessment {
+  #[serde(rename = "0")]
+  Unreliable,
+  #[serde(rename = "1")]
+  Questionable,
+  #[serde(rename = "2")]
+  Secondary,
+  #[serde(rename = "3")]
+  Direct,
+}
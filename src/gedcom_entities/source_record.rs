@@ -0,0 +1,60 @@
+use super::{
+  deserialise_optional_date_value, ChangeDate, DateValue, MultimediaLink, Note, RepositoryCitation,
+};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+/// A source of genealogical information, such as a census or a book, which citations point to.
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+pub struct SourceRecord<'input> {
+  #[serde(rename = "XREF_ID")]
+  pub xref_id: &'input str,
+  #[serde(borrow, rename = "DATA")]
+  pub data: Option<SourceData<'input>>,
+  #[serde(borrow, rename = "AUTH")]
+  pub author: Option<Cow<'input, str>>,
+  #[serde(borrow, rename = "TITL")]
+  pub title: Option<Cow<'input, str>>,
+  /// A short title, used for sorting and filing.
+  #[serde(borrow, rename = "ABBR")]
+  pub abbreviation: Option<Cow<'input, str>>,
+  #[serde(borrow, rename = "PUBL")]
+  pub publication: Option<Cow<'input, str>>,
+  #[serde(borrow, rename = "TEXT")]
+  pub text: Option<Cow<'input, str>>,
+  #[serde(borrow, rename = "REPO", default)]
+  pub repositories: Vec<RepositoryCitation<'input>>,
+  #[serde(borrow, rename = "OBJE", default)]
+  pub multimedia_links: Vec<MultimediaLink<'input>>,
+  #[serde(borrow, rename = "NOTE", default)]
+  pub notes: Vec<Note<'input>>,
+  #[serde(rename = "CHAN")]
+  pub change_date: Option<ChangeDate>,
+}
+
+/// What the source records, and who was responsible for it.
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+pub struct SourceData<'input> {
+  #[serde(borrow, rename = "EVEN", default)]
+  pub events: Vec<RecordedEvents<'input>>,
+  #[serde(borrow, rename = "AGNC")]
+  pub agency: Option<Cow<'input, str>>,
+  #[serde(borrow, rename = "NOTE", default)]
+  pub notes: Vec<Note<'input>>,
+}
+
+/// The kinds of event recorded in a source, such as `BIRT, DEAT`, and when and where they were
+/// recorded.
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+pub struct RecordedEvents<'input> {
+  #[serde(rename = "EVEN")]
+  pub events: &'input str,
+  #[serde(
+    rename = "DATE",
+    default,
+    deserialize_with = "deserialise_optional_date_value"
+  )]
+  pub date: Option<DateValue>,
+  #[serde(borrow, rename = "PLAC")]
+  pub place: Option<Cow<'input, str>>,
+}
@@ -1,7 +1,40 @@
-use serde::Deserialize;
+use super::{ChangeDate, Note};
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Deserialize, PartialEq)]
+/// A request to an ancestral file registry to process the data in the file.
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
 pub struct SubmissionRecord<'input> {
-  #[serde(rename = "NAME")]
-  pub name: &'input str,
+  #[serde(rename = "XREF_ID")]
+  pub xref_id: &'input str,
+  /// A pointer to the submitter.
+  #[serde(rename = "SUBM")]
+  pub submitter: Option<&'input str>,
+  /// The name of the family file the data is for.
+  #[serde(rename = "FAMF")]
+  pub family_file_name: Option<&'input str>,
+  /// The temple to do the ordinances in.
+  #[serde(rename = "TEMP")]
+  pub temple_code: Option<&'input str>,
+  #[serde(rename = "ANCE")]
+  pub generations_of_ancestors: Option<u16>,
+  #[serde(rename = "DESC")]
+  pub generations_of_descendants: Option<u16>,
+  #[serde(rename = "ORDI")]
+  pub ordinance_process_flag: Option<OrdinanceProcessFlag>,
+  /// A unique number given to the record by the system that made the file.
+  #[serde(rename = "RIN")]
+  pub automated_record_id: Option<&'input str>,
+  #[serde(borrow, rename = "NOTE", default)]
+  pub notes: Vec<Note<'input>>,
+  #[serde(rename = "CHAN")]
+  pub change_date: Option<ChangeDate>,
+}
+
+/// Whether to clear the data for temple ordinances.
+#[derive(Copy, Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub enum OrdinanceProcessFlag {
+  #[serde(rename = "yes")]
+  Yes,
+  #[serde(rename = "no")]
+  No,
 }
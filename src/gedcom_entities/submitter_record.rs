@@ -0,0 +1,36 @@
+use super::{Address, ChangeDate, Language, MultimediaLink, Note};
+use serde::{Deserialize, Serialize};
+
+/// The person or organisation that contributed the data in the file.
+#[derive(Debug, Deserialize, PartialEq, Serialize)]
+pub struct SubmitterRecord<'input> {
+  #[serde(rename = "XREF_ID")]
+  pub xref_id: &'input str,
+  #[serde(rename = "NAME")]
+  pub name: &'input str,
+  #[serde(borrow, rename = "ADDR")]
+  pub address: Option<Address<'input>>,
+  #[serde(rename = "PHON", default)]
+  pub phone_numbers: Vec<&'input str>,
+  #[serde(rename = "EMAIL", default)]
+  pub email_addresses: Vec<&'input str>,
+  #[serde(rename = "FAX", default)]
+  pub fax_numbers: Vec<&'input str>,
+  #[serde(rename = "WWW", default)]
+  pub web_pages: Vec<&'input str>,
+  #[serde(borrow, rename = "OBJE", default)]
+  pub multimedia_links: Vec<MultimediaLink<'input>>,
+  /// The languages the submitter would like to receive data in, in order of preference.
+  #[serde(rename = "LANG", default)]
+  pub language_preferences: Vec<Language<'input>>,
+  /// The submitter's number in an ancestral file registry.
+  #[serde(rename = "RFN")]
+  pub registered_rfn: Option<&'input str>,
+  /// A unique number given to the record by the system that made the file.
+  #[serde(rename = "RIN")]
+  pub automated_record_id: Option<&'input str>,
+  #[serde(borrow, rename = "NOTE", default)]
+  pub notes: Vec<Note<'input>>,
+  #[serde(rename = "CHAN")]
+  pub change_date: Option<ChangeDate>,
+}
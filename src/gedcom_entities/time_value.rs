@@ -1,11 +1,84 @@
+use super::DateTimeError;
 use crate::parsers::parse_time_value;
-use serde::{Deserialize, Deserializer};
+use chrono::NaiveTime;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt::{Display, Formatter};
 
 #[derive(Debug, PartialEq)]
 pub struct TimeValue {
   pub hours: u8,
   pub minutes: u8,
   pub seconds: Option<u8>,
+  /// The decimal fraction of a second, such as 123,000,000 for `.123`.
+  pub nanoseconds: Option<u32>,
+}
+
+impl TimeValue {
+  pub fn new(
+    hours: u8,
+    minutes: u8,
+    seconds: Option<u8>,
+    nanoseconds: Option<u32>,
+  ) -> Result<TimeValue, DateTimeError> {
+    if hours > 23 {
+      return Err(DateTimeError::InvalidHours(hours));
+    }
+    if minutes > 59 {
+      return Err(DateTimeError::InvalidMinutes(minutes));
+    }
+    if let Some(seconds) = seconds.filter(|&seconds| seconds > 59) {
+      return Err(DateTimeError::InvalidSeconds(seconds));
+    }
+    if let Some(nanoseconds) = nanoseconds {
+      if seconds.is_none() || nanoseconds >= 1_000_000_000 {
+        return Err(DateTimeError::InvalidFractionOfSecond(nanoseconds));
+      }
+    }
+    Ok(TimeValue {
+      hours,
+      minutes,
+      seconds,
+      nanoseconds,
+    })
+  }
+
+  pub fn to_naive_time(&self) -> Option<NaiveTime> {
+    NaiveTime::from_hms_nano_opt(
+      self.hours.into(),
+      self.minutes.into(),
+      self.seconds.unwrap_or(0).into(),
+      self.nanoseconds.unwrap_or(0),
+    )
+  }
+}
+
+impl Display for TimeValue {
+  fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+    write!(formatter, "{:02}:{:02}", self.hours, self.minutes)?;
+    if let Some(seconds) = self.seconds {
+      write!(formatter, ":{:02}", seconds)?;
+    }
+    if let Some(nanoseconds) = self.nanoseconds {
+      // Only as many digits as are needed, but always at least one.
+      let fraction = format!("{:09}", nanoseconds);
+      let fraction = fraction.trim_end_matches('0');
+      write!(
+        formatter,
+        ".{}",
+        if fraction.is_empty() { "0" } else { fraction }
+      )?;
+    }
+    Ok(())
+  }
+}
+
+impl Serialize for TimeValue {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: Serializer,
+  {
+    serializer.collect_str(self)
+  }
 }
 
 pub fn deserialise_time_value<'de, D>(deserializer: D) -> Result<TimeValue, D::Error>
@@ -23,3 +96,42 @@ where
     ))
   }
 }
+
+#[test]
+fn time_value_rejects_impossible_times() {
+  assert_eq!(
+    TimeValue::new(25, 0, None, None),
+    Err(DateTimeError::InvalidHours(25))
+  );
+  assert_eq!(
+    TimeValue::new(12, 0, Some(60), None),
+    Err(DateTimeError::InvalidSeconds(60))
+  );
+}
+
+#[test]
+fn format_time_value_round_trip() {
+  let (_, time) = parse_time_value("9:05").expect("No errors during this test");
+  assert_eq!(time.to_string(), "09:05");
+  assert_eq!(parse_time_value(&time.to_string()), Ok(("", time)));
+
+  let (_, time) = parse_time_value("15:21:24").expect("No errors during this test");
+  assert_eq!(time.to_string(), "15:21:24");
+  assert_eq!(parse_time_value(&time.to_string()), Ok(("", time)));
+}
+
+#[test]
+fn format_time_value_with_fraction_round_trip() {
+  let (_, time) = parse_time_value("15:21:24.123").expect("No errors during this test");
+  assert_eq!(time.nanoseconds, Some(123_000_000));
+  assert_eq!(time.to_string(), "15:21:24.123");
+  assert_eq!(parse_time_value(&time.to_string()), Ok(("", time)));
+}
+
+#[test]
+fn time_value_rejects_fraction_without_seconds() {
+  assert_eq!(
+    TimeValue::new(12, 0, None, Some(5)),
+    Err(DateTimeError::InvalidFractionOfSecond(5))
+  );
+}
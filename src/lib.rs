@@ -0,0 +1,8 @@
+extern crate nom;
+pub mod gedcom_data_format;
+pub mod gedcom_entities;
+mod parsers;
+pub mod relation_entities;
+#[cfg(test)]
+mod tests;
+pub mod transform_gedcom_to_relation;
@@ -1,20 +1,13 @@
 // Not sure what this does, but apparently it's now needed to compile:
 #![type_length_limit = "8388608"]
 
-extern crate nom;
-mod gedcom_data_format;
-mod gedcom_entities;
-mod parsers;
-mod relation_entities;
-mod tests;
-mod transform_gedcom_to_relation;
-
 use clap::{App, Arg};
-use gedcom_data_format::from_str;
-use gedcom_entities::Record;
+use gedcom_parser::{
+  gedcom_data_format::from_str, gedcom_entities::Record,
+  transform_gedcom_to_relation::transform_gedcom_to_relation,
+};
 use serde_json::to_string_pretty;
 use std::fs::{read_to_string, write};
-use transform_gedcom_to_relation::transform_gedcom_to_relation;
 
 fn main() {
   let app = App::new("Gedcom Parser")
@@ -1,8 +1,10 @@
 mod parse_date;
 mod parse_gedcom_line;
+mod parse_name;
 mod parse_time_value;
 mod utilities;
 
 pub use parse_date::*;
 pub use parse_gedcom_line::*;
+pub use parse_name::parse_name_value;
 pub use parse_time_value::parse_time_value;
@@ -1,27 +1,207 @@
 use super::utilities::{from_decimal, is_decimal_digit};
-use crate::gedcom_entities::{DateExact, DateGreg, Month, Month::*};
+use crate::gedcom_entities::{
+  Date, DateApproximated, DateExact, DateFren, DateGreg, DateHebr, DateJuln, DatePeriod, DateRange,
+  DateValue, FrenchMonth, HebrewMonth, Month, Month::*,
+};
 use nom::{
   branch::alt,
-  bytes::complete::{tag, tag_no_case, take_while_m_n},
+  bytes::complete::{tag, tag_no_case, take_till, take_while_m_n},
   combinator::{map, map_res},
-  sequence::tuple,
+  sequence::{delimited, preceded, tuple},
   IResult,
 };
 
-// TODO Add date validation
+pub fn parse_date_value(input: &str) -> IResult<&str, DateValue> {
+  alt((
+    map(parse_date_period, DateValue::Period),
+    map(parse_date_range, DateValue::Range),
+    map(parse_date_approximated, DateValue::Approximated),
+    parse_date_interpreted,
+    map(parse_date_phrase, DateValue::Phrase),
+    map(parse_date, DateValue::Date),
+  ))(input)
+}
+
+fn parse_date_period(input: &str) -> IResult<&str, DatePeriod> {
+  alt((
+    map(
+      tuple((
+        parse_keyword("FROM"),
+        parse_date,
+        parse_space,
+        parse_keyword("TO"),
+        parse_date,
+      )),
+      |(_, from, _, _, to)| DatePeriod::FromTo(from, to),
+    ),
+    map(
+      preceded(parse_keyword("FROM"), parse_date),
+      DatePeriod::From,
+    ),
+    map(preceded(parse_keyword("TO"), parse_date), DatePeriod::To),
+  ))(input)
+}
+
+fn parse_date_range(input: &str) -> IResult<&str, DateRange> {
+  alt((
+    map(
+      preceded(parse_keyword("BEF"), parse_date),
+      DateRange::Before,
+    ),
+    map(preceded(parse_keyword("AFT"), parse_date), DateRange::After),
+    map(
+      tuple((
+        parse_keyword("BET"),
+        parse_date,
+        parse_space,
+        parse_keyword("AND"),
+        parse_date,
+      )),
+      |(_, start, _, _, end)| DateRange::Between(start, end),
+    ),
+  ))(input)
+}
+
+fn parse_date_approximated(input: &str) -> IResult<&str, DateApproximated> {
+  alt((
+    map(
+      preceded(parse_keyword("ABT"), parse_date),
+      DateApproximated::About,
+    ),
+    map(
+      preceded(parse_keyword("CAL"), parse_date),
+      DateApproximated::Calculated,
+    ),
+    map(
+      preceded(parse_keyword("EST"), parse_date),
+      DateApproximated::Estimated,
+    ),
+  ))(input)
+}
+
+fn parse_date_interpreted(input: &str) -> IResult<&str, DateValue> {
+  map(
+    tuple((
+      parse_keyword("INT"),
+      parse_date,
+      parse_space,
+      parse_date_phrase,
+    )),
+    |(_, date, _, phrase)| DateValue::Interpreted(date, phrase),
+  )(input)
+}
+
+fn parse_date_phrase(input: &str) -> IResult<&str, String> {
+  map(
+    delimited(tag("("), take_till(|c| c == ')'), tag(")")),
+    String::from,
+  )(input)
+}
+
+/// Parses a date modifier keyword, such as `ABT`, and the space after it.
+fn parse_keyword<'a>(keyword: &'static str) -> impl Fn(&'a str) -> IResult<&'a str, &'a str> {
+  move |input| {
+    let (remaining_input, (keyword, _)) = tuple((tag_no_case(keyword), parse_space))(input)?;
+    Ok((remaining_input, keyword))
+  }
+}
+
+/// Parses a complete Gregorian date, rejecting days that are not in the month.
 pub fn parse_date_exact(input: &str) -> IResult<&str, DateExact> {
-  let (remaining_input, (day, _, month, _, year)) =
-    tuple((parse_day, parse_space, parse_month, parse_space, parse_year))(input)?;
+  map_res(
+    tuple((
+      parse_day,
+      parse_space,
+      parse_month,
+      parse_space,
+      parse_year_number,
+    )),
+    |(day, _, month, _, year)| DateExact::new(day, month, year),
+  )(input)
+}
+
+/// Parses a date in any calendar. Dates without a calendar escape are Gregorian.
+pub fn parse_date(input: &str) -> IResult<&str, Date> {
+  alt((
+    map(
+      preceded(parse_calendar_escape("@#DGREGORIAN@"), parse_date_greg),
+      Date::Gregorian,
+    ),
+    map(
+      preceded(parse_calendar_escape("@#DJULIAN@"), parse_date_juln),
+      Date::Julian,
+    ),
+    map(
+      preceded(parse_calendar_escape("@#DHEBREW@"), parse_date_hebr),
+      Date::Hebrew,
+    ),
+    map(
+      preceded(parse_calendar_escape("@#DFRENCH R@"), parse_date_fren),
+      Date::French,
+    ),
+    map(parse_date_greg, Date::Gregorian),
+  ))(input)
+}
 
-  Ok((remaining_input, DateExact { day, month, year }))
+fn parse_calendar_escape<'a>(
+  escape: &'static str,
+) -> impl Fn(&'a str) -> IResult<&'a str, &'a str> {
+  move |input| {
+    let (remaining_input, (escape, _)) = tuple((tag_no_case(escape), parse_space))(input)?;
+    Ok((remaining_input, escape))
+  }
 }
 
-// TODO Add date validation
+/// Parses a Gregorian date, rejecting days that are not in the month and mismatched dual years.
+/// Dates in the other calendars are also rejected if the day is not in the month.
 pub fn parse_date_greg(input: &str) -> IResult<&str, DateGreg> {
-  let (remaining_input, (day, _, month, _, year)) =
-    tuple((parse_day, parse_space, parse_month, parse_space, parse_year))(input)?;
+  map_res(
+    parse_calendar_date(parse_month, parse_greg_year),
+    |(day, month, (year, dual_year))| DateGreg::new(day, month, year, dual_year),
+  )(input)
+}
+
+pub fn parse_date_juln(input: &str) -> IResult<&str, DateJuln> {
+  map_res(
+    parse_calendar_date(parse_month, parse_year),
+    |(day, month, year)| DateJuln::new(day, month, year),
+  )(input)
+}
+
+pub fn parse_date_hebr(input: &str) -> IResult<&str, DateHebr> {
+  map_res(
+    parse_calendar_date(parse_hebrew_month, parse_year),
+    |(day, month, year)| DateHebr::new(day, month, year),
+  )(input)
+}
 
-  Ok((remaining_input, DateGreg { day, month, year }))
+pub fn parse_date_fren(input: &str) -> IResult<&str, DateFren> {
+  map_res(
+    parse_calendar_date(parse_french_month, parse_year),
+    |(day, month, year)| DateFren::new(day, month, year),
+  )(input)
+}
+
+type CalendarDate<M, Y> = (Option<u8>, Option<M>, Y);
+
+/// Parses `DAY MONTH YEAR`, `MONTH YEAR` or `YEAR`, with the months and years of a given calendar.
+fn parse_calendar_date<'a, M, Y>(
+  parse_month: fn(&'a str) -> IResult<&'a str, M>,
+  parse_year: fn(&'a str) -> IResult<&'a str, Y>,
+) -> impl Fn(&'a str) -> IResult<&'a str, CalendarDate<M, Y>> {
+  move |input| {
+    alt((
+      map(
+        tuple((parse_day, parse_space, parse_month, parse_space, parse_year)),
+        |(day, _, month, _, year)| (Some(day), Some(month), year),
+      ),
+      map(
+        tuple((parse_month, parse_space, parse_year)),
+        |(month, _, year)| (None, Some(month), year),
+      ),
+      map(parse_year, |year| (None, None, year)),
+    ))(input)
+  }
 }
 
 fn parse_day(input: &str) -> IResult<&str, u8> {
@@ -51,9 +231,563 @@ fn parse_month(input: &str) -> IResult<&str, Month> {
   Ok((remaining_input, month))
 }
 
-// TODO Add support for /YY year modifier
+fn parse_hebrew_month(input: &str) -> IResult<&str, HebrewMonth> {
+  alt((
+    map(tag_no_case("TSH"), |_| HebrewMonth::Tishri),
+    map(tag_no_case("CSH"), |_| HebrewMonth::Cheshvan),
+    map(tag_no_case("KSL"), |_| HebrewMonth::Kislev),
+    map(tag_no_case("TVT"), |_| HebrewMonth::Tevet),
+    map(tag_no_case("SHV"), |_| HebrewMonth::Shevat),
+    map(tag_no_case("ADR"), |_| HebrewMonth::Adar),
+    map(tag_no_case("ADS"), |_| HebrewMonth::AdarSheni),
+    map(tag_no_case("NSN"), |_| HebrewMonth::Nisan),
+    map(tag_no_case("IYR"), |_| HebrewMonth::Iyar),
+    map(tag_no_case("SVN"), |_| HebrewMonth::Sivan),
+    map(tag_no_case("TMZ"), |_| HebrewMonth::Tammuz),
+    map(tag_no_case("AAV"), |_| HebrewMonth::Av),
+    map(tag_no_case("ELL"), |_| HebrewMonth::Elul),
+  ))(input)
+}
+
+fn parse_french_month(input: &str) -> IResult<&str, FrenchMonth> {
+  alt((
+    map(tag_no_case("VEND"), |_| FrenchMonth::Vendemiaire),
+    map(tag_no_case("BRUM"), |_| FrenchMonth::Brumaire),
+    map(tag_no_case("FRIM"), |_| FrenchMonth::Frimaire),
+    map(tag_no_case("NIVO"), |_| FrenchMonth::Nivose),
+    map(tag_no_case("PLUV"), |_| FrenchMonth::Pluviose),
+    map(tag_no_case("VENT"), |_| FrenchMonth::Ventose),
+    map(tag_no_case("GERM"), |_| FrenchMonth::Germinal),
+    map(tag_no_case("FLOR"), |_| FrenchMonth::Floreal),
+    map(tag_no_case("PRAI"), |_| FrenchMonth::Prairial),
+    map(tag_no_case("MESS"), |_| FrenchMonth::Messidor),
+    map(tag_no_case("THER"), |_| FrenchMonth::Thermidor),
+    map(tag_no_case("FRUC"), |_| FrenchMonth::Fructidor),
+    map(tag_no_case("COMP"), |_| FrenchMonth::JoursComplementaires),
+  ))(input)
+}
+
+/// Parses a Gregorian year, which may be dual-dated with the last two digits of the new-style
+/// year, as in `1731/32`. Returns the year and the full new-style year.
+fn parse_greg_year(input: &str) -> IResult<&str, (i16, Option<i16>)> {
+  alt((
+    map(
+      tuple((
+        parse_year_number,
+        tag("/"),
+        map_res(take_while_m_n(2, 2, is_decimal_digit), str::parse::<i16>),
+      )),
+      |(year, _, dual_year)| (year, Some(new_style_year(year, dual_year))),
+    ),
+    map(parse_year, |year| (year, None)),
+  ))(input)
+}
+
+/// The new-style year is always later than the old-style year, even across a century, as in
+/// `1699/00`.
+fn new_style_year(year: i16, last_two_digits: i16) -> i16 {
+  let new_style_year = year - year % 100 + last_two_digits;
+  if new_style_year > year {
+    new_style_year
+  } else {
+    new_style_year + 100
+  }
+}
+
+/// Parses a year, with an optional `B.C.` era marker. Years before the common era are negative.
 fn parse_year(input: &str) -> IResult<&str, i16> {
-  map_res(take_while_m_n(3, 4, is_decimal_digit), |year| {
-    i16::from_str_radix(year, 10)
-  })(input)
+  alt((
+    map(
+      tuple((parse_year_number, parse_space, tag_no_case("B.C."))),
+      |(year, _, _)| -year,
+    ),
+    parse_year_number,
+  ))(input)
+}
+
+fn parse_year_number(input: &str) -> IResult<&str, i16> {
+  map_res(take_while_m_n(1, 4, is_decimal_digit), str::parse)(input)
+}
+
+#[test]
+fn parse_date_value_date() {
+  // Arrange
+  let input = "3 MAR 1901";
+
+  // Act
+  let (remaining_text, date) = parse_date_value(input).unwrap();
+
+  // Assert
+  assert_eq!(remaining_text, "");
+  assert_eq!(
+    date,
+    DateValue::Date(Date::Gregorian(DateGreg {
+      day: Some(3),
+      month: Some(March),
+      year: 1901,
+      dual_year: None
+    }))
+  );
+}
+
+#[test]
+fn parse_date_value_approximated() {
+  // Arrange
+  let input = "abt 3 MAR 1901";
+
+  // Act
+  let (remaining_text, date) = parse_date_value(input).unwrap();
+
+  // Assert
+  assert_eq!(remaining_text, "");
+  assert_eq!(
+    date,
+    DateValue::Approximated(DateApproximated::About(Date::Gregorian(DateGreg {
+      day: Some(3),
+      month: Some(March),
+      year: 1901,
+      dual_year: None
+    })))
+  );
+}
+
+#[test]
+fn parse_date_value_range() {
+  // Arrange
+  let input = "BET 1 JAN 1880 AND 31 DEC 1885";
+
+  // Act
+  let (remaining_text, date) = parse_date_value(input).unwrap();
+
+  // Assert
+  assert_eq!(remaining_text, "");
+  assert_eq!(
+    date,
+    DateValue::Range(DateRange::Between(
+      Date::Gregorian(DateGreg {
+        day: Some(1),
+        month: Some(January),
+        year: 1880,
+        dual_year: None
+      }),
+      Date::Gregorian(DateGreg {
+        day: Some(31),
+        month: Some(December),
+        year: 1885,
+        dual_year: None
+      })
+    ))
+  );
+}
+
+#[test]
+fn parse_date_value_period() {
+  // Arrange
+  let input = "FROM 1 JAN 1900 TO 1 JAN 1910";
+
+  // Act
+  let (remaining_text, date) = parse_date_value(input).unwrap();
+
+  // Assert
+  assert_eq!(remaining_text, "");
+  assert_eq!(
+    date,
+    DateValue::Period(DatePeriod::FromTo(
+      Date::Gregorian(DateGreg {
+        day: Some(1),
+        month: Some(January),
+        year: 1900,
+        dual_year: None
+      }),
+      Date::Gregorian(DateGreg {
+        day: Some(1),
+        month: Some(January),
+        year: 1910,
+        dual_year: None
+      })
+    ))
+  );
+}
+
+#[test]
+fn parse_date_value_open_period() {
+  // Arrange
+  let input = "TO 1 JAN 1910";
+
+  // Act
+  let (remaining_text, date) = parse_date_value(input).unwrap();
+
+  // Assert
+  assert_eq!(remaining_text, "");
+  assert_eq!(
+    date,
+    DateValue::Period(DatePeriod::To(Date::Gregorian(DateGreg {
+      day: Some(1),
+      month: Some(January),
+      year: 1910,
+      dual_year: None
+    })))
+  );
+}
+
+#[test]
+fn parse_date_value_interpreted() {
+  // Arrange
+  let input = "INT 3 MAR 1901 (Third of March, 1901)";
+
+  // Act
+  let (remaining_text, date) = parse_date_value(input).unwrap();
+
+  // Assert
+  assert_eq!(remaining_text, "");
+  assert_eq!(
+    date,
+    DateValue::Interpreted(
+      Date::Gregorian(DateGreg {
+        day: Some(3),
+        month: Some(March),
+        year: 1901,
+        dual_year: None
+      }),
+      "Third of March, 1901".to_string()
+    )
+  );
+}
+
+#[test]
+fn parse_date_value_phrase() {
+  // Arrange
+  let input = "(Spring, before the war)";
+
+  // Act
+  let (remaining_text, date) = parse_date_value(input).unwrap();
+
+  // Assert
+  assert_eq!(remaining_text, "");
+  assert_eq!(
+    date,
+    DateValue::Phrase("Spring, before the war".to_string())
+  );
+}
+
+#[test]
+fn parse_date_greg_month_and_year() {
+  // Arrange
+  let input = "JUN 1890";
+
+  // Act
+  let (remaining_text, date) = parse_date_greg(input).unwrap();
+
+  // Assert
+  assert_eq!(remaining_text, "");
+  assert_eq!(
+    date,
+    DateGreg {
+      day: None,
+      month: Some(June),
+      year: 1890,
+      dual_year: None
+    }
+  );
+}
+
+#[test]
+fn parse_date_greg_year_only() {
+  // Arrange
+  let input = "1890";
+
+  // Act
+  let (remaining_text, date) = parse_date_greg(input).unwrap();
+
+  // Assert
+  assert_eq!(remaining_text, "");
+  assert_eq!(
+    date,
+    DateGreg {
+      day: None,
+      month: None,
+      year: 1890,
+      dual_year: None
+    }
+  );
+}
+
+#[test]
+fn parse_date_greg_dual_year() {
+  // Arrange
+  let input = "11 FEB 1731/32";
+
+  // Act
+  let (remaining_text, date) = parse_date_greg(input).unwrap();
+
+  // Assert
+  assert_eq!(remaining_text, "");
+  assert_eq!(
+    date,
+    DateGreg {
+      day: Some(11),
+      month: Some(February),
+      year: 1731,
+      dual_year: Some(1732)
+    }
+  );
+}
+
+#[test]
+fn parse_date_greg_dual_year_across_century() {
+  // Arrange
+  let input = "MAR 1699/00";
+
+  // Act
+  let (remaining_text, date) = parse_date_greg(input).unwrap();
+
+  // Assert
+  assert_eq!(remaining_text, "");
+  assert_eq!(date.dual_year, Some(1700));
+}
+
+#[test]
+fn parse_date_greg_before_common_era() {
+  // Arrange
+  let input = "15 MAR 44 B.C.";
+
+  // Act
+  let (remaining_text, date) = parse_date_greg(input).unwrap();
+
+  // Assert
+  assert_eq!(remaining_text, "");
+  assert_eq!(
+    date,
+    DateGreg {
+      day: Some(15),
+      month: Some(March),
+      year: -44,
+      dual_year: None
+    }
+  );
+}
+
+#[test]
+fn parse_date_value_short_years() {
+  // Arrange
+  let input = "BET 9 AND 79";
+
+  // Act
+  let (remaining_text, date) = parse_date_value(input).unwrap();
+
+  // Assert
+  assert_eq!(remaining_text, "");
+  assert_eq!(
+    date,
+    DateValue::Range(DateRange::Between(
+      Date::Gregorian(DateGreg {
+        day: None,
+        month: None,
+        year: 9,
+        dual_year: None
+      }),
+      Date::Gregorian(DateGreg {
+        day: None,
+        month: None,
+        year: 79,
+        dual_year: None
+      })
+    ))
+  );
+}
+
+#[test]
+fn parse_date_exact_leap_day() {
+  // Arrange
+  let valid_input = "29 FEB 2020";
+  let invalid_input = "29 FEB 1900";
+
+  // Act
+  let valid_result = parse_date_exact(valid_input);
+  let invalid_result = parse_date_exact(invalid_input);
+
+  // Assert
+  assert_eq!(
+    valid_result,
+    Ok((
+      "",
+      DateExact {
+        day: 29,
+        month: February,
+        year: 2020
+      }
+    ))
+  );
+  assert!(invalid_result.is_err());
+}
+
+#[test]
+fn parse_date_value_impossible_date() {
+  // Arrange
+  let input = "31 FEB 2020";
+
+  // Act
+  let result = parse_date_value(input);
+
+  // Assert
+  assert!(result.is_err());
+}
+
+#[test]
+fn parse_date_value_partial_dates() {
+  // Arrange
+  let input = "BET 1880 AND MAR 1885";
+
+  // Act
+  let (remaining_text, date) = parse_date_value(input).unwrap();
+
+  // Assert
+  assert_eq!(remaining_text, "");
+  assert_eq!(
+    date,
+    DateValue::Range(DateRange::Between(
+      Date::Gregorian(DateGreg {
+        day: None,
+        month: None,
+        year: 1880,
+        dual_year: None
+      }),
+      Date::Gregorian(DateGreg {
+        day: None,
+        month: Some(March),
+        year: 1885,
+        dual_year: None
+      })
+    ))
+  );
+}
+
+#[test]
+fn parse_date_value_calendar_escapes() {
+  // Arrange
+  let input = "BET @#DJULIAN@ 2 SEP 1752 AND @#DGREGORIAN@ 14 SEP 1752";
+
+  // Act
+  let (remaining_text, date) = parse_date_value(input).unwrap();
+
+  // Assert
+  assert_eq!(remaining_text, "");
+  assert_eq!(
+    date,
+    DateValue::Range(DateRange::Between(
+      Date::Julian(DateJuln {
+        day: Some(2),
+        month: Some(September),
+        year: 1752
+      }),
+      Date::Gregorian(DateGreg {
+        day: Some(14),
+        month: Some(September),
+        year: 1752,
+        dual_year: None
+      })
+    ))
+  );
+}
+
+#[test]
+fn parse_date_hebrew() {
+  // Arrange
+  let input = "@#DHEBREW@ 15 NSN 5779";
+
+  // Act
+  let (remaining_text, date) = parse_date(input).unwrap();
+
+  // Assert
+  assert_eq!(remaining_text, "");
+  assert_eq!(
+    date,
+    Date::Hebrew(DateHebr {
+      day: Some(15),
+      month: Some(HebrewMonth::Nisan),
+      year: 5779
+    })
+  );
+}
+
+#[test]
+fn parse_date_french() {
+  // Arrange
+  let input = "@#DFRENCH R@ COMP 11";
+
+  // Act
+  let (remaining_text, date) = parse_date(input).unwrap();
+
+  // Assert
+  assert_eq!(remaining_text, "");
+  assert_eq!(
+    date,
+    Date::French(DateFren {
+      day: None,
+      month: Some(FrenchMonth::JoursComplementaires),
+      year: 11
+    })
+  );
+}
+
+#[test]
+fn parse_date_impossible_dates_in_other_calendars() {
+  // Arrange
+  let inputs = [
+    "@#DJULIAN@ 31 FEB 1700",
+    "@#DJULIAN@ 29 FEB 1701",
+    "@#DHEBREW@ 30 ADR 5780",
+    "@#DHEBREW@ 1 ADS 5780",
+    "@#DFRENCH R@ 31 VEND 11",
+    "@#DFRENCH R@ 6 COMP 10",
+  ];
+
+  for input in inputs {
+    // Act
+    let result = parse_date(input);
+
+    // Assert
+    assert!(result.is_err(), "Expected {} to be rejected", input);
+  }
+}
+
+#[test]
+fn parse_date_leap_days_in_other_calendars() {
+  // Arrange
+  let inputs = [
+    "@#DJULIAN@ 29 FEB 1700",
+    "@#DHEBREW@ 30 ADR 5779",
+    "@#DHEBREW@ 1 ADS 5779",
+    "@#DFRENCH R@ 6 COMP 11",
+  ];
+  let expected_dates = [
+    Date::Julian(DateJuln {
+      day: Some(29),
+      month: Some(February),
+      year: 1700,
+    }),
+    Date::Hebrew(DateHebr {
+      day: Some(30),
+      month: Some(HebrewMonth::Adar),
+      year: 5779,
+    }),
+    Date::Hebrew(DateHebr {
+      day: Some(1),
+      month: Some(HebrewMonth::AdarSheni),
+      year: 5779,
+    }),
+    Date::French(DateFren {
+      day: Some(6),
+      month: Some(FrenchMonth::JoursComplementaires),
+      year: 11,
+    }),
+  ];
+
+  for (input, expected_date) in inputs.iter().zip(expected_dates) {
+    // Act
+    let result = parse_date(input);
+
+    // Assert
+    assert_eq!(result, Ok(("", expected_date)));
+  }
 }
@@ -2,7 +2,7 @@ use super::utilities::{from_decimal, is_decimal_digit};
 use nom::{
   branch::alt,
   bytes::complete::{tag as specific_characters, take_till1, take_while1, take_while_m_n},
-  combinator::{map_res, opt},
+  combinator::{map_res, opt, recognize},
   sequence::{preceded, tuple},
   IResult,
 };
@@ -19,16 +19,16 @@ fn parse_level(input: &str) -> IResult<&str, u8> {
 // XREF ID
 // =======
 
+// The delimiting `@` characters are kept, so that an XREF ID compares equal to the pointers that
+// reference it (_e.g._ `1 SUBM @SUBM1@`).
 type XrefId<'input> = &'input str;
 
-fn parse_xref_id(input: &str) -> IResult<&str, XrefId> {
-  let (remaining_input, (_, id, _)) = tuple((
+fn parse_xref_id(input: &str) -> IResult<&str, XrefId<'_>> {
+  recognize(tuple((
     specific_characters("@"),
     take_while1(|c: char| c.is_alphanumeric()),
     specific_characters("@"),
-  ))(input)?;
-
-  Ok((remaining_input, id))
+  )))(input)
 }
 
 // ===
@@ -115,7 +115,7 @@ pub struct GedcomLine<'input> {
   pub value: Option<&'input str>,
 }
 
-pub fn parse_gedcom_line(input: &str) -> IResult<&str, GedcomLine> {
+pub fn parse_gedcom_line(input: &str) -> IResult<&str, GedcomLine<'_>> {
   let (remaining_input, (level, xref_id, _delim, tag, value, _terminator)) = tuple((
     parse_level,
     opt(preceded(parse_delim, parse_xref_id)),
@@ -199,6 +199,27 @@ fn parse_gedcom_line_no_line_value() {
   );
 }
 
+#[test]
+fn parse_gedcom_line_xref_id() {
+  // Arrange
+  let input = "0 @I1@ INDI\n";
+
+  // Act
+  let (remaining_text, gedcom_line) = parse_gedcom_line(input).unwrap();
+
+  // Assert
+  assert_eq!(remaining_text, "");
+  assert_eq!(
+    gedcom_line,
+    GedcomLine {
+      level: 0,
+      xref_id: Some("@I1@"),
+      tag: "INDI",
+      value: None,
+    }
+  );
+}
+
 #[test]
 fn parse_gedcom_line_no_level() {
   // Arrange
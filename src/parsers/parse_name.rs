@@ -0,0 +1,98 @@
+use crate::gedcom_entities::NameValue;
+use nom::{
+  bytes::complete::{tag, take_till},
+  combinator::{opt, rest},
+  sequence::delimited,
+  IResult,
+};
+use std::borrow::Cow;
+
+/// Parses a name value such as `Gavin /Henderson/ Jr.`, where the surname is between slashes. A
+/// missing closing slash ends the surname at the end of the value.
+pub fn parse_name_value(input: &str) -> IResult<&str, NameValue<'_>> {
+  let (remaining_input, given) = take_till(is_slash)(input)?;
+  let (remaining_input, surname) =
+    opt(delimited(tag("/"), take_till(is_slash), opt(tag("/"))))(remaining_input)?;
+  let (remaining_input, suffix) = rest(remaining_input)?;
+
+  Ok((
+    remaining_input,
+    NameValue {
+      given: non_empty(given.trim()).map(Cow::Borrowed),
+      surname: surname.map(|surname| Cow::Borrowed(surname.trim())),
+      suffix: non_empty(suffix.trim()).map(Cow::Borrowed),
+    },
+  ))
+}
+
+fn is_slash(character: char) -> bool {
+  character == '/'
+}
+
+fn non_empty(input: &str) -> Option<&str> {
+  if input.is_empty() {
+    None
+  } else {
+    Some(input)
+  }
+}
+
+#[test]
+fn parse_name_value_with_surname() {
+  // Arrange
+  let input = "Gavin /Henderson/";
+
+  // Act
+  let (remaining_text, name) = parse_name_value(input).unwrap();
+
+  // Assert
+  assert_eq!(remaining_text, "");
+  assert_eq!(
+    name,
+    NameValue {
+      given: Some("Gavin".into()),
+      surname: Some("Henderson".into()),
+      suffix: None
+    }
+  );
+}
+
+#[test]
+fn parse_name_value_with_suffix_and_no_given_name() {
+  // Arrange
+  let input = "/Henderson/ Jr.";
+
+  // Act
+  let (remaining_text, name) = parse_name_value(input).unwrap();
+
+  // Assert
+  assert_eq!(remaining_text, "");
+  assert_eq!(
+    name,
+    NameValue {
+      given: None,
+      surname: Some("Henderson".into()),
+      suffix: Some("Jr.".into())
+    }
+  );
+}
+
+#[test]
+fn parse_name_value_without_surname() {
+  // Arrange
+  let input = "Gavin";
+
+  // Act
+  let (remaining_text, name) = parse_name_value(input).unwrap();
+
+  // Assert
+  assert_eq!(remaining_text, "");
+  assert_eq!(
+    name,
+    NameValue {
+      given: Some("Gavin".into()),
+      surname: None,
+      suffix: None
+    }
+  );
+}
@@ -7,24 +7,24 @@ use nom::{
   IResult,
 };
 
-// TODO Add time validation
-// TODO Add decimal fraction of a second
+/// Parses `HH:MM`, `HH:MM:SS` or `HH:MM:SS.FS`, rejecting hours, minutes and seconds that are out
+/// of range.
 pub fn parse_time_value(input: &str) -> IResult<&str, TimeValue> {
-  let (remaining_input, (hours, _, minutes, seconds)) = tuple((
-    parse_hours,
-    parse_separator,
-    parse_minutes,
-    opt(preceded(parse_separator, parse_seconds)),
-  ))(input)?;
-
-  Ok((
-    remaining_input,
-    TimeValue {
-      hours,
-      minutes,
-      seconds,
+  map_res(
+    tuple((
+      parse_hours,
+      parse_separator,
+      parse_minutes,
+      opt(preceded(
+        parse_separator,
+        tuple((parse_seconds, opt(preceded(tag("."), parse_fraction)))),
+      )),
+    )),
+    |(hours, _, minutes, seconds)| match seconds {
+      Some((seconds, nanoseconds)) => TimeValue::new(hours, minutes, Some(seconds), nanoseconds),
+      None => TimeValue::new(hours, minutes, None, None),
     },
-  ))
+  )(input)
 }
 
 fn parse_hours(input: &str) -> IResult<&str, u8> {
@@ -42,3 +42,64 @@ fn parse_minutes(input: &str) -> IResult<&str, u8> {
 fn parse_seconds(input: &str) -> IResult<&str, u8> {
   map_res(take_while_m_n(1, 2, is_decimal_digit), from_decimal)(input)
 }
+
+/// Parses a decimal fraction of a second, to nanosecond precision.
+fn parse_fraction(input: &str) -> IResult<&str, u32> {
+  map_res(take_while_m_n(1, 9, is_decimal_digit), |digits: &str| {
+    format!("{:0<9}", digits).parse()
+  })(input)
+}
+
+#[test]
+fn parse_time_value_with_seconds() {
+  // Arrange
+  let input = "15:21:24";
+
+  // Act
+  let (remaining_text, time) = parse_time_value(input).unwrap();
+
+  // Assert
+  assert_eq!(remaining_text, "");
+  assert_eq!(
+    time,
+    TimeValue {
+      hours: 15,
+      minutes: 21,
+      seconds: Some(24),
+      nanoseconds: None
+    }
+  );
+}
+
+#[test]
+fn parse_time_value_out_of_range() {
+  // Arrange
+  let input = "25:99:99";
+
+  // Act
+  let result = parse_time_value(input);
+
+  // Assert
+  assert!(result.is_err());
+}
+
+#[test]
+fn parse_time_value_with_fraction_of_second() {
+  // Arrange
+  let input = "15:21:24.5";
+
+  // Act
+  let (remaining_text, time) = parse_time_value(input).unwrap();
+
+  // Assert
+  assert_eq!(remaining_text, "");
+  assert_eq!(
+    time,
+    TimeValue {
+      hours: 15,
+      minutes: 21,
+      seconds: Some(24),
+      nanoseconds: Some(500_000_000)
+    }
+  );
+}
@@ -1,7 +1,7 @@
 pub fn from_decimal(input: &str) -> Result<u8, std::num::ParseIntError> {
-  u8::from_str_radix(input, 10)
+  input.parse()
 }
 
 pub fn is_decimal_digit(character: char) -> bool {
-  character.is_digit(10)
+  character.is_ascii_digit()
 }
@@ -5,21 +5,23 @@ pub struct FamilyTree<'input> {
   #[serde(rename = "Persons")]
   pub persons: Vec<Person<'input>>,
   #[serde(rename = "Familys")]
-  pub familys: Vec<()>,
+  pub familys: Vec<Family>,
   #[serde(rename = "Childs")]
-  pub childs: Vec<()>,
+  pub childs: Vec<Child>,
   #[serde(rename = "SourceRepos")]
-  pub source_repos: Vec<()>,
+  pub source_repos: Vec<SourceRepo<'input>>,
   #[serde(rename = "MasterSources")]
-  pub master_sources: Vec<()>,
+  pub master_sources: Vec<MasterSource<'input>>,
   #[serde(rename = "Medias")]
-  pub medias: Vec<()>,
+  pub medias: Vec<Media<'input>>,
   #[serde(rename = "FactTypes")]
-  pub fact_types: Vec<()>,
+  pub fact_types: Vec<FactType>,
 }
 
 #[derive(Debug, Serialize)]
 pub struct Person<'input> {
+  #[serde(rename = "Id")]
+  pub id: u32,
   #[serde(rename = "IsLiving")]
   pub is_living: bool,
   #[serde(rename = "Gender")]
@@ -46,16 +48,113 @@ pub struct Name<'input> {
 pub struct Fact<'input> {
   #[serde(rename = "FactTypeId")]
   pub fact_type_id: u16,
+  /// The value of an attribute, such as an occupation. Events have no value.
+  #[serde(rename = "Value")]
+  pub value: Option<&'input str>,
   #[serde(rename = "DateDetail")]
-  pub date_detail: String,
+  pub date_detail: Option<String>,
   #[serde(rename = "Place")]
   pub place: Place<'input>,
   #[serde(rename = "Preferred")]
   pub preferred: bool,
 }
 
+/// A kind of name or fact, which names and facts refer to by its id.
+#[derive(Debug, PartialEq, Serialize)]
+pub struct FactType {
+  #[serde(rename = "Id")]
+  pub id: u16,
+  #[serde(rename = "Name")]
+  pub name: &'static str,
+}
+
 #[derive(Debug, Serialize)]
 pub struct Place<'input> {
   #[serde(rename = "PlaceName")]
   pub place_name: Option<&'input str>,
+  #[serde(rename = "Latitude")]
+  pub latitude: Option<f64>,
+  #[serde(rename = "Longitude")]
+  pub longitude: Option<f64>,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+pub struct MasterSource<'input> {
+  #[serde(rename = "Id")]
+  pub id: u32,
+  #[serde(rename = "Title")]
+  pub title: Option<&'input str>,
+  #[serde(rename = "Author")]
+  pub author: Option<&'input str>,
+  #[serde(rename = "Publication")]
+  pub publication: Option<&'input str>,
+  #[serde(rename = "Text")]
+  pub text: Option<&'input str>,
+  #[serde(rename = "SourceRepoId")]
+  pub source_repo_id: Option<u32>,
+  #[serde(rename = "CallNumber")]
+  pub call_number: Option<&'input str>,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+pub struct SourceRepo<'input> {
+  #[serde(rename = "Id")]
+  pub id: u32,
+  #[serde(rename = "Name")]
+  pub name: &'input str,
+  /// The address lines, city, post code and country, one per line.
+  #[serde(rename = "Address")]
+  pub address: Option<String>,
+  #[serde(rename = "PhoneNumber")]
+  pub phone_number: Option<&'input str>,
+  #[serde(rename = "Email")]
+  pub email: Option<&'input str>,
+  #[serde(rename = "WebSite")]
+  pub web_site: Option<&'input str>,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+pub struct Media<'input> {
+  #[serde(rename = "Id")]
+  pub id: u32,
+  #[serde(rename = "PersonId")]
+  pub person_id: u32,
+  #[serde(rename = "FileName")]
+  pub file_name: &'input str,
+  #[serde(rename = "Title")]
+  pub title: Option<&'input str>,
+  #[serde(rename = "Format")]
+  pub format: Option<&'input str>,
+  #[serde(rename = "IsPrimary")]
+  pub is_primary: bool,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+pub struct Family {
+  #[serde(rename = "Id")]
+  pub id: u32,
+  #[serde(rename = "FatherId")]
+  pub father_id: Option<u32>,
+  #[serde(rename = "MotherId")]
+  pub mother_id: Option<u32>,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+pub struct Child {
+  #[serde(rename = "Id")]
+  pub id: u32,
+  #[serde(rename = "FamilyId")]
+  pub family_id: u32,
+  #[serde(rename = "PersonId")]
+  pub person_id: u32,
+  #[serde(rename = "RelationshipType")]
+  pub relationship_type: RelationshipType,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Serialize)]
+pub enum RelationshipType {
+  Birth,
+  Adopted,
+  Foster,
+  Sealing,
 }
@@ -0,0 +1,95 @@
+#[test]
+fn event_address() {
+  use super::{individual_with_xref, parse};
+  use crate::gedcom_entities::Address;
+
+  // Arrange
+  let input = include_str!("addresses.ged");
+
+  // Act
+  let records = parse(input);
+
+  // Assert
+  let residence = individual_with_xref(&records, "@I1@").attributes[0].detail();
+  assert_eq!(
+    residence.address,
+    Some(Address {
+      address_line: "1600 Main Street\nApartment 4B\nSpringfield, IL 62701".into(),
+      line1: Some("1600 Main Street".into()),
+      line2: Some("Apartment 4B".into()),
+      line3: None,
+      city: Some("Springfield".into()),
+      state: Some("IL".into()),
+      post_code: Some("62701".into()),
+      country: Some("USA".into())
+    })
+  );
+  assert_eq!(
+    residence.phone_numbers,
+    vec!["+1 217 555 0100", "+1 217 555 0199"]
+  );
+  assert_eq!(residence.email_addresses, vec!["ellen@example.com"]);
+  assert_eq!(residence.fax_numbers, vec!["+1 217 555 0101"]);
+  assert_eq!(residence.web_pages, vec!["www.example.com/ellen"]);
+}
+
+#[test]
+fn submitter_address() {
+  use super::{parse, submitter_with_xref};
+  use crate::gedcom_entities::Address;
+
+  // Arrange
+  let input = include_str!("addresses.ged");
+
+  // Act
+  let records = parse(input);
+
+  // Assert
+  let submitter = submitter_with_xref(&records, "@SUBM1@");
+  assert_eq!(
+    submitter.address,
+    Some(Address {
+      address_line: "12 St James's Square\nLondon".into(),
+      line1: Some("12 St James's Square".into()),
+      city: Some("London".into()),
+      country: Some("England".into()),
+      ..Default::default()
+    })
+  );
+  assert_eq!(submitter.email_addresses, vec!["ada@example.com"]);
+}
+
+#[test]
+fn separate_address_lines_to_relation() {
+  use super::parse;
+  use crate::transform_gedcom_to_relation::transform_gedcom_to_relation;
+
+  // Arrange
+  let input = "0 @R1@ REPO
+1 NAME National Records of Scotland
+1 ADDR General Register House
+2 ADR1 General Register House
+2 ADR2 2 Princes Street
+2 CITY Edinburgh
+0 @R2@ REPO
+1 NAME Dundee City Archives
+1 ADDR
+2 ADR1 21 City Square
+2 CITY Dundee
+0 TRLR
+";
+  let records = parse(input);
+
+  // Act
+  let family_tree = transform_gedcom_to_relation(&records);
+
+  // Assert
+  assert_eq!(
+    family_tree.source_repos[0].address,
+    Some("General Register House\n2 Princes Street\nEdinburgh".to_string())
+  );
+  assert_eq!(
+    family_tree.source_repos[1].address,
+    Some("21 City Square\nDundee".to_string())
+  );
+}
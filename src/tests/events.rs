@@ -0,0 +1,270 @@
+#[test]
+fn individual_events() {
+  use super::{individual_with_xref, parse};
+  use crate::gedcom_entities::{
+    Date, DateApproximated, DateGreg, DateValue, EventDetail, IndividualEvent, Note, SourceCitation,
+  };
+
+  // Arrange
+  let input = include_str!("events.ged");
+
+  // Act
+  let records = parse(input);
+
+  // Assert
+  let individual = individual_with_xref(&records, "@I1@");
+  let tags: Vec<&str> = individual
+    .events
+    .iter()
+    .map(|event| match event {
+      IndividualEvent::Birth(_) => "BIRT",
+      IndividualEvent::Census(_) => "CENS",
+      IndividualEvent::Event(_) => "EVEN",
+      IndividualEvent::Death(_) => "DEAT",
+      IndividualEvent::Burial(_) => "BURI",
+      _ => "other",
+    })
+    .collect();
+  assert_eq!(tags, vec!["BIRT", "CENS", "CENS", "EVEN", "DEAT", "BURI"]);
+  assert_eq!(
+    individual.events[2],
+    IndividualEvent::Census(EventDetail {
+      date: Some(DateValue::Date(Date::Gregorian(DateGreg {
+        day: None,
+        month: None,
+        year: 1851,
+        dual_year: None
+      }))),
+      age: Some("50y"),
+      notes: vec![Note {
+        text: "Listed as a weaver,\nhead of household".into(),
+        ..Default::default()
+      }],
+      ..Default::default()
+    })
+  );
+  assert_eq!(
+    individual.events[3],
+    IndividualEvent::Event(EventDetail {
+      value: Some("Bought a farm".into()),
+      event_type: Some("Land purchase".into()),
+      date: Some(DateValue::Approximated(DateApproximated::About(
+        Date::Gregorian(DateGreg {
+          day: None,
+          month: None,
+          year: 1855,
+          dual_year: None
+        })
+      ))),
+      sources: vec![SourceCitation {
+        source: "@S1@".into(),
+        ..Default::default()
+      }],
+      ..Default::default()
+    })
+  );
+  assert_eq!(
+    individual.events[4],
+    IndividualEvent::Death(EventDetail {
+      value: Some("Y".into()),
+      ..Default::default()
+    })
+  );
+  assert_eq!(individual.events[5].detail().cause, Some("Fever".into()));
+  assert_eq!(
+    individual.events[5].detail().agency,
+    Some("Parish of Saint-Jean".into())
+  );
+}
+
+#[test]
+fn deceased_individual_to_relation() {
+  use super::parse;
+  use crate::transform_gedcom_to_relation::transform_gedcom_to_relation;
+
+  // Arrange
+  let input = include_str!("events.ged");
+  let records = parse(input);
+
+  // Act
+  let family_tree = transform_gedcom_to_relation(&records);
+
+  // Assert
+  assert!(!family_tree.persons[0].is_living);
+  let fact_type_ids: Vec<u16> = family_tree.persons[0]
+    .facts
+    .iter()
+    .map(|fact| fact.fact_type_id)
+    .collect();
+  assert_eq!(fact_type_ids, vec![405, 446, 446, 451, 431, 432, 417, 420]);
+  let burial = &family_tree.persons[0].facts[5];
+  assert_eq!(burial.date_detail, Some("12 JAN 1860".to_string()));
+}
+
+#[test]
+fn individual_attributes() {
+  use super::{individual_with_xref, parse};
+  use crate::gedcom_entities::{EventDetail, IndividualAttribute, Place};
+
+  // Arrange
+  let input = include_str!("events.ged");
+
+  // Act
+  let records = parse(input);
+
+  // Assert
+  let individual = individual_with_xref(&records, "@I1@");
+  assert_eq!(individual.attributes.len(), 2);
+  assert_eq!(
+    individual.attributes[0].detail().value,
+    Some("Weaver".into())
+  );
+  assert_eq!(
+    individual.attributes[1],
+    IndividualAttribute::Residence(EventDetail {
+      place: Some(Place {
+        name: "Lyon".into(),
+        ..Default::default()
+      }),
+      ..Default::default()
+    })
+  );
+}
+
+#[test]
+fn individual_attributes_to_relation() {
+  use super::parse;
+  use crate::transform_gedcom_to_relation::transform_gedcom_to_relation;
+
+  // Arrange
+  let input = include_str!("events.ged");
+  let records = parse(input);
+
+  // Act
+  let family_tree = transform_gedcom_to_relation(&records);
+
+  // Assert
+  let occupation = &family_tree.persons[0].facts[6];
+  assert_eq!(occupation.fact_type_id, 417);
+  assert_eq!(occupation.value, Some("Weaver"));
+  assert_eq!(
+    occupation.date_detail,
+    Some("FROM 1820 TO 1855".to_string())
+  );
+  let residence = &family_tree.persons[0].facts[7];
+  assert_eq!(residence.fact_type_id, 420);
+  assert_eq!(residence.place.place_name, Some("Lyon"));
+}
+
+#[test]
+fn every_fact_type_id_has_a_fact_type() {
+  use super::parse;
+  use crate::{
+    gedcom_entities::{IndividualAttribute, IndividualEvent},
+    transform_gedcom_to_relation::transform_gedcom_to_relation,
+  };
+
+  // Arrange
+  let fact_lines: String = IndividualEvent::TAGS
+    .iter()
+    .chain(IndividualAttribute::TAGS)
+    .map(|tag| format!("1 {} Value\n", tag))
+    .collect();
+  let input = format!(
+    "0 @I1@ INDI\n1 NAME Gavin /Henderson/\n{}0 TRLR\n",
+    fact_lines
+  );
+  let records = parse(&input);
+
+  // Act
+  let family_tree = transform_gedcom_to_relation(&records);
+
+  // Assert
+  let person = &family_tree.persons[0];
+  assert_eq!(
+    person.facts.len(),
+    IndividualEvent::TAGS.len() + IndividualAttribute::TAGS.len()
+  );
+  let fact_type_ids = person
+    .names
+    .iter()
+    .map(|name| name.fact_type_id)
+    .chain(person.facts.iter().map(|fact| fact.fact_type_id));
+  for fact_type_id in fact_type_ids {
+    assert!(
+      family_tree
+        .fact_types
+        .iter()
+        .any(|fact_type| fact_type.id == fact_type_id),
+      "No fact type for fact type id {}",
+      fact_type_id
+    );
+  }
+}
+
+#[test]
+fn continued_event_detail_values() {
+  use crate::{gedcom_data_format::from_str, gedcom_entities::EventDetail};
+
+  // Arrange
+  let input = "1 EVEN
+2 TYPE Land purch
+3 CONC ase
+2 CAUS Fev
+3 CONC er
+2 AGNC Parish of
+3 CONC  Saint-Jean
+2 ADDR
+3 ADR1 1600 Main
+4 CONC  Street
+3 CITY Spring
+4 CONC field
+";
+
+  // Act
+  let detail: EventDetail = from_str(input).expect("No errors during this test");
+
+  // Assert
+  assert_eq!(detail.event_type.as_deref(), Some("Land purchase"));
+  assert_eq!(detail.cause.as_deref(), Some("Fever"));
+  assert_eq!(detail.agency.as_deref(), Some("Parish of Saint-Jean"));
+  let address = detail.address.expect("No errors during this test");
+  assert_eq!(address.line1.as_deref(), Some("1600 Main Street"));
+  assert_eq!(address.city.as_deref(), Some("Springfield"));
+}
+
+#[test]
+fn event_detail_with_repeated_tags_in_between_others() {
+  use super::{individual_with_xref, parse};
+
+  // Arrange
+  let input = "0 @I1@ INDI
+1 BIRT
+2 SOUR @S1@
+3 PAGE Folio 3
+2 NOTE hi
+2 PHON 555-0100
+2 SOUR @S2@
+2 NOTE there
+2 PHON 555-0199
+1 SEX M
+0 TRLR
+";
+
+  // Act
+  let records = parse(input);
+
+  // Assert
+  let individual = individual_with_xref(&records, "@I1@");
+  let birth = individual.events[0].detail();
+  let sources: Vec<&str> = birth
+    .sources
+    .iter()
+    .map(|source| source.source.as_ref())
+    .collect();
+  assert_eq!(sources, vec!["@S1@", "@S2@"]);
+  assert_eq!(birth.sources[0].page.as_deref(), Some("Folio 3"));
+  let notes: Vec<&str> = birth.notes.iter().map(|note| note.text.as_ref()).collect();
+  assert_eq!(notes, vec!["hi", "there"]);
+  assert_eq!(birth.phone_numbers, vec!["555-0100", "555-0199"]);
+}
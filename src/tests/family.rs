@@ -0,0 +1,194 @@
+#[test]
+fn family() {
+  use super::{family_with_xref, individual_with_xref, parse};
+  use crate::gedcom_entities::{
+    ChildToFamilyLink, Date, DateGreg, DateValue, EventDetail, Family, FamilyEvent, Month::June,
+    Pedigree::Birth, Place, Record, SpouseToFamilyLink,
+  };
+
+  // Arrange
+  let input = include_str!("family.ged");
+
+  // Act
+  let records = parse(input);
+
+  // Assert
+  assert_eq!(records.len(), 7);
+  let child = individual_with_xref(&records, "@I1@");
+  assert_eq!(
+    child.child_to_family_links,
+    vec![ChildToFamilyLink {
+      family: "@F1@",
+      pedigree: Some(Birth)
+    }]
+  );
+  assert_eq!(child.spouse_to_family_links, vec![]);
+  let father = individual_with_xref(&records, "@I2@");
+  assert_eq!(father.child_to_family_links, vec![]);
+  assert_eq!(
+    father.spouse_to_family_links,
+    vec![SpouseToFamilyLink { family: "@F1@" }]
+  );
+  assert_eq!(
+    family_with_xref(&records, "@F1@"),
+    &Family {
+      xref_id: "@F1@",
+      husband: Some("@I2@"),
+      wife: Some("@I3@"),
+      children: vec!["@I1@"],
+      number_of_children: Some(1),
+      events: vec![FamilyEvent::Marriage(EventDetail {
+        date: Some(DateValue::Date(Date::Gregorian(DateGreg {
+          day: Some(12),
+          month: Some(June),
+          year: 1985,
+          dual_year: None
+        }))),
+        place: Some(Place {
+          name: "Perth".into(),
+          ..Default::default()
+        }),
+        value: Some("Y".into()),
+        ..Default::default()
+      })],
+      multimedia_links: vec![],
+      notes: vec![],
+      uid: None,
+      change_date: None
+    }
+  );
+  assert_eq!(records.last(), Some(&Record::Trailer));
+}
+
+#[test]
+fn family_to_relation() {
+  use super::parse;
+  use crate::{
+    relation_entities::{Child, Family, RelationshipType::Birth},
+    transform_gedcom_to_relation::transform_gedcom_to_relation,
+  };
+
+  // Arrange
+  let input = include_str!("family.ged");
+  let records = parse(input);
+
+  // Act
+  let family_tree = transform_gedcom_to_relation(&records);
+
+  // Assert
+  assert_eq!(family_tree.persons.len(), 3);
+  assert_eq!(
+    family_tree
+      .persons
+      .iter()
+      .map(|person| (person.id, person.names[0].given_names, person.facts.len()))
+      .collect::<Vec<_>>(),
+    vec![(1, "Gavin", 1), (2, "Alastair", 0), (3, "Morag", 0)]
+  );
+  assert_eq!(
+    family_tree.familys,
+    vec![Family {
+      id: 1,
+      father_id: Some(2),
+      mother_id: Some(3)
+    }]
+  );
+  assert_eq!(
+    family_tree.childs,
+    vec![Child {
+      id: 1,
+      family_id: 1,
+      person_id: 1,
+      relationship_type: Birth
+    }]
+  );
+}
+
+#[test]
+fn individual_without_name_or_birth_to_relation() {
+  use super::parse;
+  use crate::transform_gedcom_to_relation::transform_gedcom_to_relation;
+
+  // Arrange
+  let input = "0 @I1@ INDI\n1 SEX F\n0 TRLR\n";
+  let records = parse(input);
+
+  // Act
+  let family_tree = transform_gedcom_to_relation(&records);
+
+  // Assert
+  assert_eq!(family_tree.persons.len(), 1);
+  assert_eq!(family_tree.persons[0].gender, 2);
+  assert!(family_tree.persons[0].names.is_empty());
+  assert!(family_tree.persons[0].facts.is_empty());
+}
+
+#[test]
+fn partial_birth_date_to_relation() {
+  use super::parse;
+  use crate::transform_gedcom_to_relation::transform_gedcom_to_relation;
+
+  // Arrange
+  let input = "0 @I1@ INDI\n1 BIRT\n2 DATE JUN 1890\n0 @I2@ INDI\n1 BIRT\n2 DATE 1890\n0 TRLR\n";
+  let records = parse(input);
+
+  // Act
+  let family_tree = transform_gedcom_to_relation(&records);
+
+  // Assert
+  assert_eq!(
+    family_tree.persons[0].facts[0].date_detail,
+    Some("JUN 1890".to_string())
+  );
+  assert_eq!(
+    family_tree.persons[1].facts[0].date_detail,
+    Some("1890".to_string())
+  );
+}
+
+#[test]
+fn name_without_pieces_to_relation() {
+  use super::parse;
+  use crate::transform_gedcom_to_relation::transform_gedcom_to_relation;
+
+  // Arrange
+  let input = "0 @I1@ INDI\n1 NAME Morag /Henderson/\n0 TRLR\n";
+  let records = parse(input);
+
+  // Act
+  let family_tree = transform_gedcom_to_relation(&records);
+
+  // Assert
+  assert_eq!(family_tree.persons[0].names[0].given_names, "Morag");
+  assert_eq!(family_tree.persons[0].names[0].surnames, "Henderson");
+}
+
+#[test]
+fn family_with_interleaved_children_and_events() {
+  use super::{family_with_xref, parse};
+  use crate::gedcom_entities::FamilyEvent;
+
+  // Arrange
+  let input = "0 @F1@ FAM
+1 HUSB @I1@
+1 CHIL @I2@
+1 MARR
+2 DATE 1901
+1 NOTE Married twice
+1 CHIL @I3@
+1 MARR
+2 DATE 1921
+1 DIV
+0 TRLR
+";
+
+  // Act
+  let records = parse(input);
+
+  // Assert
+  let family = family_with_xref(&records, "@F1@");
+  assert_eq!(family.children, vec!["@I2@", "@I3@"]);
+  assert_eq!(family.notes.len(), 1);
+  let event_tags: Vec<&str> = family.events.iter().map(FamilyEvent::tag).collect();
+  assert_eq!(event_tags, vec!["MARR", "MARR", "DIV"]);
+}
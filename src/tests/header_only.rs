@@ -3,8 +3,9 @@ fn header_only() {
   use crate::{
     gedcom_data_format::from_str,
     gedcom_entities::{
-      Address, Business, CharacterSet::Utf8, DateExact, Gedcom, GedcomForm::LineageLinked, Header,
-      Language::English, Month::April, Source, TimeValue, TransmissionDateTime,
+      Address, Business, CharacterEncoding, CharacterSet::Utf8, DateExact, Gedcom,
+      GedcomForm::LineageLinked, Header, Language::English, Month::April, Source, TimeValue,
+      TransmissionDateTime,
     },
   };
 
@@ -25,35 +26,155 @@ fn header_only() {
         business: Some(Business {
           name: "DC Thomson Family History",
           address: Some(Address {
-            address_line: "The Glebe, 6 Chapel Place, Rivington Street",
-            city: Some("London"),
-            post_code: Some("EC2A 3DQ"),
-            country: Some("England")
+            address_line: "The Glebe, 6 Chapel Place, Rivington Street".into(),
+            city: Some("London".into()),
+            post_code: Some("EC2A 3DQ".into()),
+            country: Some("England".into()),
+            ..Default::default()
           }),
-          web_page: vec!["www.findmypast.com"]
-        })
+          phone_numbers: vec![],
+          email_addresses: vec![],
+          fax_numbers: vec![],
+          web_pages: vec!["www.findmypast.com"]
+        }),
+        data: None
       },
-      receiving_sytem_name: "FINDMYPAST",
-      transmission_date_time: TransmissionDateTime {
+      receiving_system_name: Some("FINDMYPAST"),
+      transmission_date_time: Some(TransmissionDateTime {
         date: DateExact {
           day: 15,
           month: April,
           year: 2020
         },
-        time: TimeValue {
+        time: Some(TimeValue {
           hours: 15,
           minutes: 21,
           seconds: Some(24),
-        }
-      },
-      file_name: "Henderson Family Tree.ged",
-      submission_record_id: "@SUBM1@",
+          nanoseconds: None
+        })
+      }),
+      file_name: Some("Henderson Family Tree.ged"),
+      submitter_id: "@SUBM1@",
+      submission_id: None,
+      copyright: None,
       gedcom: Gedcom {
         version_number: "5.5.1",
         form: LineageLinked
       },
-      character_set: Utf8,
-      language: Some(English)
+      character_encoding: CharacterEncoding {
+        character_set: Utf8,
+        version: None
+      },
+      language: Some(English),
+      place_hierarchy: None,
+      content_description: None,
+      root_individual_id: Some("@I1@"),
     }
   )
 }
+
+#[test]
+fn full_header() {
+  use super::{header, parse};
+  use crate::gedcom_entities::{
+    CharacterEncoding, CharacterSet::Ansel, DateExact, ElectronicSource, Language::Norwegian,
+    Month::January,
+  };
+
+  // Arrange
+  let input = include_str!("header-full.ged");
+
+  // Act
+  let records = parse(input);
+
+  // Assert
+  let header = header(&records);
+  assert_eq!(
+    header.source.data,
+    Some(ElectronicSource {
+      name: "Parish registers of Kirkwall".into(),
+      publication_date: Some(DateExact {
+        day: 1,
+        month: January,
+        year: 1998
+      }),
+      copyright: Some("Copyright 1998 Orkney Library\nAll rights reserved.".into())
+    })
+  );
+  assert_eq!(header.receiving_system_name, None);
+  assert_eq!(
+    header
+      .transmission_date_time
+      .as_ref()
+      .and_then(|date_time| date_time.to_naive_date_time())
+      .map(|date_time| date_time.to_string()),
+    Some("2001-07-03 00:00:00".to_string())
+  );
+  assert_eq!(header.file_name, None);
+  assert_eq!(header.submitter_id, "@U1@");
+  assert_eq!(header.submission_id, Some("@SUBN1@"));
+  assert_eq!(header.copyright, Some("Copyright 2001 Inga Flett".into()));
+  assert_eq!(
+    header.character_encoding,
+    CharacterEncoding {
+      character_set: Ansel,
+      version: Some("ANSI Z39.47-1985")
+    }
+  );
+  assert_eq!(header.language, Some(Norwegian));
+  assert_eq!(
+    header.content_description,
+    Some("Descendants of Magnus Flett of Kirkwall.".into())
+  );
+  assert_eq!(header.root_individual_id, None);
+}
+
+#[test]
+fn root_individual() {
+  use super::{header, parse};
+
+  // Arrange
+  let input = include_str!("one-node.ged");
+  let records = parse(input);
+  let header = header(&records);
+
+  // Act
+  let root_individual = header.root_individual(&records);
+
+  // Assert
+  assert_eq!(
+    root_individual.map(|individual| individual.xref_id),
+    Some("@I1@")
+  );
+}
+
+#[test]
+fn language_names() {
+  use super::{parse, submitter_with_xref};
+  use crate::{gedcom_data_format::to_string, gedcom_entities::Language};
+
+  // Arrange
+  let input = "0 @SUBM1@ SUBM
+1 NAME Ada Lovelace
+1 LANG ENGLISH
+1 LANG Serbo_Croa
+1 LANG Klingon
+0 TRLR
+";
+
+  // Act
+  let records = parse(input);
+
+  // Assert
+  let submitter = submitter_with_xref(&records, "@SUBM1@");
+  assert_eq!(
+    submitter.language_preferences,
+    vec![
+      Language::English,
+      Language::SerboCroatian,
+      Language::Other("Klingon")
+    ]
+  );
+  let output = to_string(&records).expect("No errors during this test");
+  assert!(output.contains("1 LANG English\n1 LANG Serbo_Croa\n1 LANG Klingon\n"));
+}
@@ -1,2 +1,63 @@
+mod addresses;
+mod events;
+mod family;
 mod header_only;
+mod multimedia;
+mod notes;
 mod one_node;
+mod places;
+mod round_trip;
+mod sources;
+mod submitters;
+
+use crate::{
+  gedcom_data_format::from_str,
+  gedcom_entities::{
+    Family, Header, Individual, MultimediaRecord, NoteRecord, Record, RepositoryRecord,
+    SourceRecord, SubmissionRecord, SubmitterRecord,
+  },
+};
+
+/// Reads the records of a test input, which must be valid.
+fn parse(input: &str) -> Vec<Record<'_>> {
+  from_str(input).expect("Test inputs are valid")
+}
+
+fn header<'a, 'input>(records: &'a [Record<'input>]) -> &'a Header<'input> {
+  records
+    .iter()
+    .find_map(|record| match record {
+      Record::Header(header) => Some(header),
+      _ => None,
+    })
+    .expect("Expected a header")
+}
+
+/// Declares a function for each kind of record that finds the record with a cross-reference ID,
+/// so that tests do not depend on where a record is in its file.
+macro_rules! record_with_xref {
+  ($($function:ident => $variant:ident($entity:ident),)*) => {
+    $(
+      fn $function<'a, 'input>(records: &'a [Record<'input>], xref_id: &str) -> &'a $entity<'input> {
+        records
+          .iter()
+          .find_map(|record| match record {
+            Record::$variant(entity) if entity.xref_id == xref_id => Some(entity),
+            _ => None,
+          })
+          .unwrap_or_else(|| panic!("Expected a {} {}", stringify!($variant), xref_id))
+      }
+    )*
+  };
+}
+
+record_with_xref! {
+  individual_with_xref => Individual(Individual),
+  family_with_xref => Family(Family),
+  source_with_xref => Source(SourceRecord),
+  repository_with_xref => Repository(RepositoryRecord),
+  multimedia_with_xref => Multimedia(MultimediaRecord),
+  note_with_xref => Note(NoteRecord),
+  submitter_with_xref => Submitter(SubmitterRecord),
+  submission_with_xref => Submission(SubmissionRecord),
+}
@@ -0,0 +1,133 @@
+#[test]
+fn multimedia_links() {
+  use super::{family_with_xref, individual_with_xref, parse};
+  use crate::gedcom_entities::{Crop, MultimediaFile, MultimediaFormat, MultimediaLink};
+
+  // Arrange
+  let input = include_str!("multimedia.ged");
+
+  // Act
+  let records = parse(input);
+
+  // Assert
+  let individual = individual_with_xref(&records, "@I1@");
+  assert_eq!(
+    individual.multimedia_links,
+    vec![
+      MultimediaLink {
+        object: Some("@O1@"),
+        is_primary: true,
+        crop: Some(Crop {
+          left: 10,
+          top: 20,
+          width: 300,
+          height: 400
+        }),
+        ..Default::default()
+      },
+      MultimediaLink {
+        object: Some("@O2@"),
+        ..Default::default()
+      }
+    ]
+  );
+  assert_eq!(
+    individual.events[0].detail().multimedia_links,
+    vec![MultimediaLink {
+      files: vec![MultimediaFile {
+        file: "certificates/birth.pdf",
+        format: Some(MultimediaFormat {
+          format: "pdf",
+          media: Some("document"),
+          ..Default::default()
+        }),
+        ..Default::default()
+      }],
+      title: Some("Birth certificate".into()),
+      ..Default::default()
+    }]
+  );
+  assert_eq!(
+    family_with_xref(&records, "@F1@").multimedia_links,
+    vec![MultimediaLink {
+      files: vec![MultimediaFile {
+        file: "wedding.jpg",
+        ..Default::default()
+      }],
+      ..Default::default()
+    }]
+  );
+}
+
+#[test]
+fn multimedia_record() {
+  use super::{multimedia_with_xref, parse};
+  use crate::gedcom_entities::{MultimediaFile, MultimediaFormat, MultimediaRecord, Note};
+
+  // Arrange
+  let input = include_str!("multimedia.ged");
+
+  // Act
+  let records = parse(input);
+
+  // Assert
+  assert_eq!(
+    multimedia_with_xref(&records, "@O1@"),
+    &MultimediaRecord {
+      xref_id: "@O1@",
+      files: vec![MultimediaFile {
+        file: "photos/morag.jpg",
+        format: Some(MultimediaFormat {
+          format: "jpg",
+          media_type: Some("photo"),
+          ..Default::default()
+        }),
+        title: Some("Morag in 1890".into())
+      }],
+      notes: vec![Note {
+        text: "Taken in Perth".into(),
+        ..Default::default()
+      }],
+      sources: vec![],
+      change_date: None
+    }
+  );
+}
+
+#[test]
+fn multimedia_to_relation() {
+  use super::parse;
+  use crate::{
+    relation_entities::Media, transform_gedcom_to_relation::transform_gedcom_to_relation,
+  };
+
+  // Arrange
+  let input = include_str!("multimedia.ged");
+  let records = parse(input);
+
+  // Act
+  let family_tree = transform_gedcom_to_relation(&records);
+
+  // Assert
+  assert_eq!(
+    family_tree.medias,
+    vec![
+      Media {
+        id: 1,
+        person_id: 1,
+        file_name: "photos/morag.jpg",
+        title: Some("Morag in 1890"),
+        format: Some("jpg"),
+        is_primary: true
+      },
+      Media {
+        id: 2,
+        person_id: 1,
+        file_name: "certificates/birth.pdf",
+        title: Some("Birth certificate"),
+        format: Some("pdf"),
+        is_primary: false
+      }
+    ]
+  );
+}
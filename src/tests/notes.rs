@@ -0,0 +1,83 @@
+#[test]
+fn notes() {
+  use super::{family_with_xref, individual_with_xref, note_with_xref, parse, source_with_xref};
+  use crate::gedcom_entities::{Note, NoteRecord, SourceCitation};
+
+  // Arrange
+  let input = include_str!("notes.ged");
+
+  // Act
+  let records = parse(input);
+
+  // Assert
+  let individual = individual_with_xref(&records, "@I1@");
+  assert_eq!(individual.name[0].notes[0].text, "Also spelt Morrag");
+  assert_eq!(
+    individual.events[0].detail().notes[0].note_xref_id(),
+    Some("@N1@")
+  );
+  assert_eq!(
+    individual.notes,
+    vec![
+      Note {
+        text: "Emigrated to Canada in 1885 and settled near Winnipeg.\nWrote home every Christmas."
+          .into(),
+        sources: vec![SourceCitation {
+          source: "@S1@".into(),
+          ..Default::default()
+        }]
+      },
+      Note {
+        text: "@N2@".into(),
+        ..Default::default()
+      }
+    ]
+  );
+  assert_eq!(individual.notes[0].note_xref_id(), None);
+  assert_eq!(
+    family_with_xref(&records, "@F1@").notes[0].note_xref_id(),
+    Some("@N1@")
+  );
+  assert_eq!(
+    source_with_xref(&records, "@S1@").notes[0].text,
+    "Kept by her sister"
+  );
+  assert_eq!(
+    note_with_xref(&records, "@N1@"),
+    &NoteRecord {
+      xref_id: "@N1@",
+      text:
+        "The registers for Perth were rebuilt after the fire of 1861.\nSome entries may be missing."
+          .into(),
+      sources: vec![],
+      change_date: None
+    }
+  );
+}
+
+#[test]
+fn resolve_notes() {
+  use super::{individual_with_xref, parse};
+  use crate::gedcom_entities::NoteResolver;
+
+  // Arrange
+  let input = include_str!("notes.ged");
+  let records = parse(input);
+  let individual = individual_with_xref(&records, "@I1@");
+
+  // Act
+  let note_resolver = NoteResolver::new(&records);
+
+  // Assert
+  assert_eq!(
+    note_resolver.resolve(&individual.events[0].detail().notes[0]),
+    Some(
+      "The registers for Perth were rebuilt after the fire of 1861.\nSome entries may be missing."
+    )
+  );
+  assert_eq!(
+    note_resolver.resolve(&individual.name[0].notes[0]),
+    Some("Also spelt Morrag")
+  );
+  assert_eq!(note_resolver.resolve(&individual.notes[1]), None);
+}
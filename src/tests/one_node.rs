@@ -3,16 +3,16 @@ fn one_node() {
   use crate::{
     gedcom_data_format::from_str,
     gedcom_entities::{
-      Address, BirthEvent, Business, ChangeDate, ChangeDateDateTime,
+      Address, Business, ChangeDate, ChangeDateDateTime, CharacterEncoding,
       CharacterSet::Utf8,
-      DateExact, DateGreg, Gedcom,
+      Date, DateExact, DateGreg, DateValue, EventDetail, Gedcom,
       GedcomForm::LineageLinked,
-      Header, Individual,
+      Header, Individual, IndividualEvent,
       Language::English,
       Month::{April, January},
-      PersonalName, Record,
+      NameValue, PersonalName, Place, Record,
       SexValue::Male,
-      Source, SubmissionRecord, TimeValue, TransmissionDateTime,
+      Source, SubmitterRecord, TimeValue, TransmissionDateTime,
     },
   };
 
@@ -34,59 +34,105 @@ fn one_node() {
         business: Some(Business {
           name: "DC Thomson Family History",
           address: Some(Address {
-            address_line: "The Glebe, 6 Chapel Place, Rivington Street",
-            city: Some("London"),
-            post_code: Some("EC2A 3DQ"),
-            country: Some("England")
+            address_line: "The Glebe, 6 Chapel Place, Rivington Street".into(),
+            city: Some("London".into()),
+            post_code: Some("EC2A 3DQ".into()),
+            country: Some("England".into()),
+            ..Default::default()
           }),
-          web_page: vec!["www.findmypast.com"]
-        })
+          phone_numbers: vec![],
+          email_addresses: vec![],
+          fax_numbers: vec![],
+          web_pages: vec!["www.findmypast.com"]
+        }),
+        data: None
       },
-      receiving_sytem_name: "FINDMYPAST",
-      transmission_date_time: TransmissionDateTime {
+      receiving_system_name: Some("FINDMYPAST"),
+      transmission_date_time: Some(TransmissionDateTime {
         date: DateExact {
           day: 15,
           month: April,
           year: 2020
         },
-        time: TimeValue {
+        time: Some(TimeValue {
           hours: 15,
           minutes: 21,
           seconds: Some(24),
-        }
-      },
-      file_name: "Henderson Family Tree.ged",
-      submission_record_id: "@SUBM1@",
+          nanoseconds: None
+        })
+      }),
+      file_name: Some("Henderson Family Tree.ged"),
+      submitter_id: "@SUBM1@",
+      submission_id: None,
+      copyright: None,
       gedcom: Gedcom {
         version_number: "5.5.1",
         form: LineageLinked
       },
-      character_set: Utf8,
-      language: Some(English)
+      character_encoding: CharacterEncoding {
+        character_set: Utf8,
+        version: None
+      },
+      language: Some(English),
+      place_hierarchy: None,
+      content_description: None,
+      root_individual_id: Some("@I1@"),
     })
   );
   assert_eq!(
     records[1],
-    Record::SubmissionRecord(SubmissionRecord { name: "Not known" })
+    Record::Submitter(SubmitterRecord {
+      xref_id: "@SUBM1@",
+      name: "Not known",
+      address: None,
+      phone_numbers: vec![],
+      email_addresses: vec![],
+      fax_numbers: vec![],
+      web_pages: vec![],
+      multimedia_links: vec![],
+      language_preferences: vec![],
+      registered_rfn: None,
+      automated_record_id: None,
+      notes: vec![],
+      change_date: None
+    })
   );
   assert_eq!(
     records[2],
     Record::Individual(Individual {
+      xref_id: "@I1@",
       name: vec![PersonalName {
-        given_name: "Gavin",
-        surname: "Henderson",
-        is_primary: true
+        value: Some(NameValue {
+          given: Some("Gavin".into()),
+          surname: Some("Henderson".into()),
+          suffix: None
+        }),
+        given_name: Some("Gavin"),
+        surname: Some("Henderson"),
+        is_primary: true,
+        ..Default::default()
       }],
       sex: Some(Male),
-      birth_event: Some(BirthEvent {
+      events: vec![IndividualEvent::Birth(EventDetail {
         is_primary: true,
-        place: Some("Dundee"),
-        date: DateGreg {
-          day: 1,
-          month: January,
-          year: 1990
-        }
-      }),
+        place: Some(Place {
+          name: "Dundee".into(),
+          ..Default::default()
+        }),
+        date: Some(DateValue::Date(Date::Gregorian(DateGreg {
+          day: Some(1),
+          month: Some(January),
+          year: 1990,
+          dual_year: None
+        }))),
+        ..Default::default()
+      })],
+      attributes: vec![],
+      child_to_family_links: vec![],
+      spouse_to_family_links: vec![],
+      sources: vec![],
+      multimedia_links: vec![],
+      notes: vec![],
       uid: Some("9ACF01CA-A40C-4AF5-8905-D6678B6288BE"),
       change_date: Some(ChangeDate {
         date_time: ChangeDateDateTime {
@@ -98,7 +144,8 @@ fn one_node() {
           time: TimeValue {
             hours: 16,
             minutes: 19,
-            seconds: Some(21)
+            seconds: Some(21),
+            nanoseconds: None
           }
         }
       })
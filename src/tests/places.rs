@@ -0,0 +1,214 @@
+#[test]
+fn places() {
+  use super::{individual_with_xref, parse};
+  use crate::gedcom_entities::{Map, Place, PlaceVariation};
+
+  // Arrange
+  let input = include_str!("places.ged");
+
+  // Act
+  let records = parse(input);
+
+  // Assert
+  let individual = individual_with_xref(&records, "@I1@");
+  assert_eq!(
+    individual.events[0].detail().place,
+    Some(Place {
+      name: "京都, , 日本".into(),
+      phonetic_variations: vec![PlaceVariation {
+        name: "Kyoto, , Nihon".into(),
+        variation_type: Some("kana")
+      }],
+      romanized_variations: vec![PlaceVariation {
+        name: "Kyoto, , Nippon".into(),
+        variation_type: Some("romaji")
+      }],
+      map: Some(Map {
+        latitude: 35.0116,
+        longitude: 135.768
+      }),
+      ..Default::default()
+    })
+  );
+  assert_eq!(
+    individual.attributes[0].detail().place,
+    Some(Place {
+      name: "Lima, Peru".into(),
+      form: Some("City, Country"),
+      map: Some(Map {
+        latitude: -12.0464,
+        longitude: -77.0428
+      }),
+      ..Default::default()
+    })
+  );
+}
+
+#[test]
+fn place_jurisdictions() {
+  use super::{individual_with_xref, parse};
+  use crate::gedcom_entities::Jurisdiction;
+
+  // Arrange
+  let input = include_str!("places.ged");
+  let records = parse(input);
+  let individual = individual_with_xref(&records, "@I1@");
+  let kyoto = individual.events[0].detail().place.as_ref().unwrap();
+  let lima = individual.attributes[0].detail().place.as_ref().unwrap();
+
+  // Act
+  let kyoto_jurisdictions = kyoto.jurisdictions(Some("City, County, Country"));
+  let lima_jurisdictions = lima.jurisdictions(Some("City, County, Country"));
+
+  // Assert
+  assert_eq!(
+    kyoto_jurisdictions,
+    vec![
+      Jurisdiction {
+        name: "京都",
+        level: Some("City")
+      },
+      Jurisdiction {
+        name: "",
+        level: Some("County")
+      },
+      Jurisdiction {
+        name: "日本",
+        level: Some("Country")
+      }
+    ]
+  );
+  assert_eq!(
+    lima_jurisdictions,
+    vec![
+      Jurisdiction {
+        name: "Lima",
+        level: Some("City")
+      },
+      Jurisdiction {
+        name: "Peru",
+        level: Some("Country")
+      }
+    ]
+  );
+  assert_eq!(
+    lima.jurisdictions(None)[1],
+    Jurisdiction {
+      name: "Peru",
+      level: Some("Country")
+    }
+  );
+}
+
+#[test]
+fn places_written_with_coordinates() {
+  use super::parse;
+  use crate::gedcom_data_format::to_string;
+
+  // Arrange
+  let input = include_str!("places.ged");
+  let records = parse(input);
+
+  // Act
+  let output = to_string(&records).expect("No errors during this test");
+
+  // Assert
+  assert!(output.contains("4 LATI S12.0464\n4 LONG W77.0428"));
+}
+
+#[test]
+fn places_to_relation() {
+  use super::parse;
+  use crate::transform_gedcom_to_relation::transform_gedcom_to_relation;
+
+  // Arrange
+  let input = include_str!("places.ged");
+  let records = parse(input);
+
+  // Act
+  let family_tree = transform_gedcom_to_relation(&records);
+
+  // Assert
+  let birth_place = &family_tree.persons[0].facts[0].place;
+  assert_eq!(birth_place.place_name, Some("京都, , 日本"));
+  assert_eq!(birth_place.latitude, Some(35.0116));
+  assert_eq!(birth_place.longitude, Some(135.768));
+  let residence_place = &family_tree.persons[0].facts[1].place;
+  assert_eq!(residence_place.latitude, Some(-12.0464));
+  assert_eq!(residence_place.longitude, Some(-77.0428));
+}
+
+#[test]
+fn continued_place_and_page() {
+  use super::{individual_with_xref, parse};
+  use crate::gedcom_entities::IndividualEvent;
+
+  // Arrange
+  let input = "0 @I1@ INDI
+1 BIRT
+2 PLAC Saint-Pierre-de-Chartreuse, Arrondissement de Gren
+3 CONC oble, France
+2 SOUR @S1@
+3 PAGE Registre des naissances,
+4 CONT folio 12
+0 TRLR
+";
+
+  // Act
+  let records = parse(input);
+
+  // Assert
+  let birth = match &individual_with_xref(&records, "@I1@").events[0] {
+    IndividualEvent::Birth(birth) => birth,
+    event => panic!("Expected a birth, found {:?}", event),
+  };
+  assert_eq!(
+    birth.place.as_ref().map(|place| place.name.as_ref()),
+    Some("Saint-Pierre-de-Chartreuse, Arrondissement de Grenoble, France")
+  );
+  assert_eq!(
+    birth.sources[0].page.as_deref(),
+    Some("Registre des naissances,\nfolio 12")
+  );
+}
+
+#[test]
+fn place_hierarchy_from_header() {
+  use super::{header, individual_with_xref, parse};
+  use crate::gedcom_entities::{Jurisdiction, Map, Place, PlaceHierarchy};
+
+  // Arrange
+  let input = include_str!("header-place.ged");
+
+  // Act
+  let records = parse(input);
+
+  // Assert
+  let place_hierarchy = header(&records).place_hierarchy.as_ref();
+  assert_eq!(
+    place_hierarchy,
+    Some(&PlaceHierarchy {
+      form: "City, County, Country"
+    })
+  );
+  let individual = individual_with_xref(&records, "@I1@");
+  let birth_place = individual.events[0].detail().place.as_ref().unwrap();
+  assert_eq!(
+    birth_place.jurisdictions(place_hierarchy.map(|hierarchy| hierarchy.form))[1],
+    Jurisdiction {
+      name: "Orkney",
+      level: Some("County")
+    }
+  );
+  assert_eq!(
+    individual.events[1].detail().place,
+    Some(Place {
+      name: "".into(),
+      map: Some(Map {
+        latitude: 58.981,
+        longitude: -2.96
+      }),
+      ..Default::default()
+    })
+  );
+}
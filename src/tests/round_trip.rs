@@ -0,0 +1,42 @@
+/// Declares a test that reads a fixture, writes its records back out and reads them again,
+/// expecting the same records.
+macro_rules! round_trip_test {
+  ($($name:ident: $fixture:literal,)*) => {
+    $(
+      #[test]
+      fn $name() {
+        use crate::{
+          gedcom_data_format::{from_str, to_string},
+          gedcom_entities::Record,
+        };
+
+        // Arrange
+        let input = include_str!($fixture);
+        let records: Vec<Record> = from_str(input).expect("No errors during this test");
+
+        // Act
+        let output = to_string(&records).expect("No errors during this test");
+
+        // Assert
+        let round_tripped_records: Vec<Record> =
+          from_str(&output).expect("No errors during this test");
+        assert_eq!(round_tripped_records, records);
+      }
+    )*
+  };
+}
+
+round_trip_test! {
+  addresses_round_trip: "addresses.ged",
+  events_round_trip: "events.ged",
+  family_round_trip: "family.ged",
+  full_header_round_trip: "header-full.ged",
+  header_only_round_trip: "header-only.ged",
+  header_place_round_trip: "header-place.ged",
+  multimedia_round_trip: "multimedia.ged",
+  notes_round_trip: "notes.ged",
+  one_node_round_trip: "one-node.ged",
+  places_round_trip: "places.ged",
+  sources_round_trip: "sources.ged",
+  submitters_round_trip: "submitters.ged",
+}
@@ -0,0 +1,311 @@
+#[test]
+fn source_citations() {
+  use super::{individual_with_xref, parse};
+  use crate::gedcom_entities::{
+    CertaintyAssessment::{Direct, Secondary},
+    CitationData, CitedEvent, Date, DateGreg, DateValue,
+    Month::February,
+    Note, SourceCitation,
+  };
+
+  // Arrange
+  let input = include_str!("sources.ged");
+
+  // Act
+  let records = parse(input);
+
+  // Assert
+  let individual = individual_with_xref(&records, "@I1@");
+  assert_eq!(individual.name[0].sources[0].source_xref_id(), Some("@S2@"));
+  assert_eq!(individual.name[0].sources[0].page, Some("Folio 12".into()));
+  assert_eq!(
+    individual.events[0].detail().sources,
+    vec![SourceCitation {
+      source: "@S1@".into(),
+      page: Some("Entry 42".into()),
+      event: Some(CitedEvent {
+        event_type: "BIRT",
+        role: Some("CHIL")
+      }),
+      data: Some(CitationData {
+        date: Some(DateValue::Date(Date::Gregorian(DateGreg {
+          day: Some(6),
+          month: Some(February),
+          year: 1860,
+          dual_year: None
+        }))),
+        text: vec!["Morag, daughter of John Smith, weaver".into()]
+      }),
+      quality: Some(Direct),
+      ..Default::default()
+    }]
+  );
+  assert_eq!(
+    individual.sources,
+    vec![SourceCitation {
+      source: "Family bible held by Jean Smith".into(),
+      text: vec!["Morag born 4th February".into()],
+      notes: vec![Note {
+        text: "Handwritten on the flyleaf".into(),
+        ..Default::default()
+      }],
+      quality: Some(Secondary),
+      ..Default::default()
+    }]
+  );
+  assert_eq!(individual.sources[0].source_xref_id(), None);
+}
+
+#[test]
+fn source_record() {
+  use super::{parse, source_with_xref};
+  use crate::gedcom_entities::{
+    CallNumber, Date, DateGreg, DatePeriod, DateValue, RecordedEvents, RepositoryCitation,
+    SourceData, SourceRecord,
+  };
+
+  // Arrange
+  let input = include_str!("sources.ged");
+
+  // Act
+  let records = parse(input);
+
+  // Assert
+  assert_eq!(
+    source_with_xref(&records, "@S1@"),
+    &SourceRecord {
+      xref_id: "@S1@",
+      data: Some(SourceData {
+        events: vec![RecordedEvents {
+          events: "BIRT, DEAT",
+          date: Some(DateValue::Period(DatePeriod::FromTo(
+            Date::Gregorian(DateGreg {
+              day: None,
+              month: None,
+              year: 1855,
+              dual_year: None
+            }),
+            Date::Gregorian(DateGreg {
+              day: None,
+              month: None,
+              year: 1875,
+              dual_year: None
+            })
+          ))),
+          place: Some("Perth, Scotland".into())
+        }],
+        agency: Some("General Register Office for Scotland".into()),
+        notes: vec![]
+      }),
+      author: Some("General Register Office for Scotland".into()),
+      title: Some("Statutory Registers of Births\nPerth".into()),
+      abbreviation: Some("Perth births".into()),
+      publication: Some("Edinburgh".into()),
+      text: None,
+      repositories: vec![RepositoryCitation {
+        repository: Some("@R1@"),
+        call_numbers: vec![CallNumber {
+          call_number: "1860/388/42",
+          media: Some("book")
+        }],
+        notes: vec![]
+      }],
+      multimedia_links: vec![],
+      notes: vec![],
+      change_date: None
+    }
+  );
+}
+
+#[test]
+fn source_records_to_relation() {
+  use super::parse;
+  use crate::{
+    relation_entities::MasterSource, transform_gedcom_to_relation::transform_gedcom_to_relation,
+  };
+
+  // Arrange
+  let input = include_str!("sources.ged");
+  let records = parse(input);
+
+  // Act
+  let family_tree = transform_gedcom_to_relation(&records);
+
+  // Assert
+  assert_eq!(
+    family_tree.master_sources,
+    vec![
+      MasterSource {
+        id: 1,
+        title: Some("Statutory Registers of Births\nPerth"),
+        author: Some("General Register Office for Scotland"),
+        publication: Some("Edinburgh"),
+        text: None,
+        source_repo_id: Some(1),
+        call_number: Some("1860/388/42")
+      },
+      MasterSource {
+        id: 2,
+        title: Some("Old Parish Registers"),
+        author: None,
+        publication: None,
+        text: Some("Baptisms and banns"),
+        source_repo_id: None,
+        call_number: None
+      }
+    ]
+  );
+}
+
+#[test]
+fn repository_record() {
+  use super::{parse, repository_with_xref};
+  use crate::gedcom_entities::{Address, RepositoryRecord};
+
+  // Arrange
+  let input = include_str!("sources.ged");
+
+  // Act
+  let records = parse(input);
+
+  // Assert
+  assert_eq!(
+    repository_with_xref(&records, "@R1@"),
+    &RepositoryRecord {
+      xref_id: "@R1@",
+      name: "National Records of Scotland",
+      address: Some(Address {
+        address_line: "General Register House\n2 Princes Street".into(),
+        city: Some("Edinburgh".into()),
+        post_code: Some("EH1 3YY".into()),
+        country: Some("Scotland".into()),
+        ..Default::default()
+      }),
+      phone_numbers: vec!["0131 535 1314"],
+      email_addresses: vec!["enquiries@nrscotland.gov.uk"],
+      fax_numbers: vec![],
+      web_pages: vec!["www.nrscotland.gov.uk"],
+      notes: vec![],
+      change_date: None
+    }
+  );
+}
+
+#[test]
+fn repository_records_to_relation() {
+  use super::parse;
+  use crate::{
+    relation_entities::SourceRepo, transform_gedcom_to_relation::transform_gedcom_to_relation,
+  };
+
+  // Arrange
+  let input = include_str!("sources.ged");
+  let records = parse(input);
+
+  // Act
+  let family_tree = transform_gedcom_to_relation(&records);
+
+  // Assert
+  assert_eq!(
+    family_tree.source_repos,
+    vec![SourceRepo {
+      id: 1,
+      name: "National Records of Scotland",
+      address: Some(
+        "General Register House\n2 Princes Street\nEdinburgh\nEH1 3YY\nScotland".to_string()
+      ),
+      phone_number: Some("0131 535 1314"),
+      email: Some("enquiries@nrscotland.gov.uk"),
+      web_site: Some("www.nrscotland.gov.uk")
+    }]
+  );
+}
+
+#[test]
+fn repository_citation_without_pointer() {
+  use super::{parse, source_with_xref};
+  use crate::transform_gedcom_to_relation::transform_gedcom_to_relation;
+
+  // Arrange
+  let input = "0 @S1@ SOUR
+1 TITL Parish register of Kirkcaldy
+1 REPO
+2 CALN 442/1
+0 TRLR
+";
+  let records = parse(input);
+
+  // Act
+  let family_tree = transform_gedcom_to_relation(&records);
+
+  // Assert
+  let source = source_with_xref(&records, "@S1@");
+  assert_eq!(source.repositories[0].repository, None);
+  assert_eq!(source.repositories[0].call_numbers[0].call_number, "442/1");
+  assert_eq!(family_tree.master_sources[0].source_repo_id, None);
+  assert_eq!(family_tree.master_sources[0].call_number, Some("442/1"));
+}
+
+#[test]
+fn source_record_and_citation_with_repeated_tags_in_between_others() {
+  use super::{individual_with_xref, parse, source_with_xref};
+
+  // Arrange
+  let input = "0 @I1@ INDI
+1 SOUR @S1@
+2 NOTE First note
+2 OBJE @O1@
+2 PAGE Folio 3
+2 NOTE Second note
+2 OBJE @O2@
+0 @S1@ SOUR
+1 REPO @R1@
+1 NOTE First note
+1 OBJE @O1@
+1 TITL Parish register of Kirkcaldy
+1 REPO @R2@
+2 CALN 442/1
+1 NOTE Second note
+1 OBJE @O2@
+0 TRLR
+";
+
+  // Act
+  let records = parse(input);
+
+  // Assert
+  let citation = &individual_with_xref(&records, "@I1@").sources[0];
+  let source = source_with_xref(&records, "@S1@");
+  let citation_notes: Vec<&str> = citation
+    .notes
+    .iter()
+    .map(|note| note.text.as_ref())
+    .collect();
+  let citation_objects: Vec<Option<&str>> = citation
+    .multimedia_links
+    .iter()
+    .map(|link| link.object)
+    .collect();
+  assert_eq!(citation.page.as_deref(), Some("Folio 3"));
+  assert_eq!(citation_notes, vec!["First note", "Second note"]);
+  assert_eq!(citation_objects, vec![Some("@O1@"), Some("@O2@")]);
+  let repositories: Vec<Option<&str>> = source
+    .repositories
+    .iter()
+    .map(|repository| repository.repository)
+    .collect();
+  let source_notes: Vec<&str> = source.notes.iter().map(|note| note.text.as_ref()).collect();
+  let source_objects: Vec<Option<&str>> = source
+    .multimedia_links
+    .iter()
+    .map(|link| link.object)
+    .collect();
+  assert_eq!(repositories, vec![Some("@R1@"), Some("@R2@")]);
+  assert_eq!(source.repositories[1].call_numbers[0].call_number, "442/1");
+  assert_eq!(
+    source.title.as_deref(),
+    Some("Parish register of Kirkcaldy")
+  );
+  assert_eq!(source_notes, vec!["First note", "Second note"]);
+  assert_eq!(source_objects, vec![Some("@O1@"), Some("@O2@")]);
+}
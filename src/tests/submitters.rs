@@ -0,0 +1,84 @@
+#[test]
+fn submitter_record() {
+  use super::{parse, submitter_with_xref};
+  use crate::gedcom_entities::{
+    Address, ChangeDate, ChangeDateDateTime, DateExact, Language::English, Month::March,
+    MultimediaLink, SubmitterRecord, TimeValue,
+  };
+
+  // Arrange
+  let input = include_str!("submitters.ged");
+
+  // Act
+  let records = parse(input);
+
+  // Assert
+  assert_eq!(
+    submitter_with_xref(&records, "@SUBM1@"),
+    &SubmitterRecord {
+      xref_id: "@SUBM1@",
+      name: "Ada Lovelace",
+      address: Some(Address {
+        address_line: "12 St James's Square".into(),
+        city: Some("London".into()),
+        ..Default::default()
+      }),
+      phone_numbers: vec!["020 7946 0000"],
+      email_addresses: vec![],
+      fax_numbers: vec![],
+      web_pages: vec![],
+      multimedia_links: vec![MultimediaLink {
+        object: Some("@O1@"),
+        ..Default::default()
+      }],
+      language_preferences: vec![English],
+      registered_rfn: Some("12345"),
+      automated_record_id: Some("7"),
+      notes: vec![],
+      change_date: Some(ChangeDate {
+        date_time: ChangeDateDateTime {
+          date: DateExact {
+            day: 2,
+            month: March,
+            year: 2021
+          },
+          time: TimeValue {
+            hours: 9,
+            minutes: 30,
+            seconds: Some(0),
+            nanoseconds: None
+          }
+        }
+      })
+    }
+  );
+}
+
+#[test]
+fn submission_record() {
+  use super::{parse, submission_with_xref};
+  use crate::gedcom_entities::{OrdinanceProcessFlag::No, SubmissionRecord};
+
+  // Arrange
+  let input = include_str!("submitters.ged");
+
+  // Act
+  let records = parse(input);
+
+  // Assert
+  assert_eq!(
+    submission_with_xref(&records, "@SUBN1@"),
+    &SubmissionRecord {
+      xref_id: "@SUBN1@",
+      submitter: Some("@SUBM1@"),
+      family_file_name: Some("Lovelace family"),
+      temple_code: Some("LONDO"),
+      generations_of_ancestors: Some(3),
+      generations_of_descendants: Some(2),
+      ordinance_process_flag: Some(No),
+      automated_record_id: Some("8"),
+      notes: vec![],
+      change_date: None
+    }
+  );
+}
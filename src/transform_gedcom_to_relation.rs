@@ -1,35 +1,152 @@
 use crate::gedcom_entities::{
-  Individual, Record,
+  Address, DateValue, EventDetail, Family as GedcomFamily, Individual, IndividualEvent,
+  MultimediaLink, MultimediaRecord, Pedigree, Record, RepositoryRecord,
   SexValue::{Female, Male, Undetermined},
+  SourceRecord,
+};
+use crate::relation_entities::{
+  Child, Fact, FactType, Family, FamilyTree, MasterSource, Media, Name, Person, Place,
+  RelationshipType, SourceRepo,
 };
-use crate::relation_entities::{Fact, FamilyTree, Name, Person, Place};
 use chrono::Local;
+use std::collections::HashMap;
 
 pub fn transform_gedcom_to_relation<'input>(
-  gedcom_records: &'input Vec<Record<'input>>,
+  gedcom_records: &'input [Record<'input>],
 ) -> FamilyTree<'input> {
-  if let Record::Individual(individual) = &gedcom_records[2] {
-    let person = transform_indivual_to_person(individual);
-    return FamilyTree {
-      persons: vec![person],
-      familys: vec![],
-      childs: vec![],
-      source_repos: vec![],
-      master_sources: vec![],
-      medias: vec![],
-      fact_types: vec![],
-    };
-  } else {
-    panic!();
+  let individuals: Vec<&Individual> = gedcom_records
+    .iter()
+    .filter_map(|record| match record {
+      Record::Individual(individual) => Some(individual),
+      _ => None,
+    })
+    .collect();
+  let gedcom_families = gedcom_records.iter().filter_map(|record| match record {
+    Record::Family(family) => Some(family),
+    _ => None,
+  });
+
+  // Ids are assigned in document order, starting at 1, so they are stable for a given file.
+  let person_ids: HashMap<&str, u32> = individuals
+    .iter()
+    .zip(1..)
+    .map(|(individual, id)| (individual.xref_id, id))
+    .collect();
+
+  // Looked up once per child, so the links are indexed by child and family up front.
+  let mut pedigrees: HashMap<(&str, &str), Option<Pedigree>> = HashMap::new();
+  for individual in &individuals {
+    for link in &individual.child_to_family_links {
+      pedigrees
+        .entry((individual.xref_id, link.family))
+        .or_insert(link.pedigree);
+    }
+  }
+
+  let mut familys = vec![];
+  let mut childs = vec![];
+  for (gedcom_family, family_id) in gedcom_families.zip(1..) {
+    familys.push(transform_family(gedcom_family, family_id, &person_ids));
+    for child_xref_id in &gedcom_family.children {
+      let child_id = childs.len() as u32 + 1;
+      if let Some(child) = transform_child(
+        child_xref_id,
+        gedcom_family.xref_id,
+        child_id,
+        family_id,
+        &pedigrees,
+        &person_ids,
+      ) {
+        childs.push(child);
+      }
+    }
+  }
+
+  let repositories: Vec<&RepositoryRecord> = gedcom_records
+    .iter()
+    .filter_map(|record| match record {
+      Record::Repository(repository) => Some(repository),
+      _ => None,
+    })
+    .collect();
+  let source_repo_ids: HashMap<&str, u32> = repositories
+    .iter()
+    .zip(1..)
+    .map(|(repository, id)| (repository.xref_id, id))
+    .collect();
+  let source_repos = repositories
+    .iter()
+    .map(|repository| transform_repository(repository, source_repo_ids[repository.xref_id]))
+    .collect();
+
+  let master_sources = gedcom_records
+    .iter()
+    .filter_map(|record| match record {
+      Record::Source(source) => Some(source),
+      _ => None,
+    })
+    .zip(1..)
+    .map(|(source, id)| transform_source(source, id, &source_repo_ids))
+    .collect();
+
+  let multimedia_records: HashMap<&str, &MultimediaRecord> = gedcom_records
+    .iter()
+    .filter_map(|record| match record {
+      Record::Multimedia(multimedia) => Some((multimedia.xref_id, multimedia)),
+      _ => None,
+    })
+    .collect();
+  // A media belongs to a person, so the multimedia linked to families, sources and citations
+  // that are not under an individual has nowhere to go.
+  let mut medias = vec![];
+  for individual in &individuals {
+    for link in individual_multimedia_links(individual) {
+      let media_id = medias.len() as u32 + 1;
+      if let Some(media) = transform_multimedia_link(
+        link,
+        media_id,
+        person_ids[individual.xref_id],
+        &multimedia_records,
+      ) {
+        medias.push(media);
+      }
+    }
+  }
+
+  let persons = individuals
+    .iter()
+    .map(|individual| transform_individual_to_person(individual, person_ids[individual.xref_id]))
+    .collect();
+
+  FamilyTree {
+    persons,
+    familys,
+    childs,
+    source_repos,
+    master_sources,
+    medias,
+    fact_types: FACT_TYPES
+      .iter()
+      .map(|&(_, id, name)| FactType { id, name })
+      .collect(),
   }
 }
 
-fn transform_indivual_to_person<'input>(individual: &'input Individual) -> Person<'input> {
-  let birth_event = individual.birth_event.as_ref().unwrap();
+fn transform_individual_to_person<'input>(
+  individual: &'input Individual,
+  id: u32,
+) -> Person<'input> {
   let now = Local::now();
 
   Person {
-    is_living: true,
+    id,
+    // Only a record of the individual's death or burial says that they are no longer living.
+    is_living: !individual.events.iter().any(|event| {
+      matches!(
+        event,
+        IndividualEvent::Death(_) | IndividualEvent::Burial(_) | IndividualEvent::Cremation(_)
+      )
+    }),
     gender: individual
       .sex
       .map(|sex| match sex {
@@ -39,21 +156,250 @@ fn transform_indivual_to_person<'input>(individual: &'input Individual) -> Perso
       })
       .unwrap_or(0),
     date_created: now.to_rfc2822(),
-    names: vec![Name {
-      fact_type_id: 100,
-      given_names: individual.name[0].given_name,
-      surnames: individual.name[0].surname,
-    }],
-    facts: vec![Fact {
-      fact_type_id: 405,
-      date_detail: format!(
-        "{} {} {}",
-        birth_event.date.day, birth_event.date.month, birth_event.date.year
-      ),
-      place: Place {
-        place_name: birth_event.place,
-      },
-      preferred: true,
-    }],
+    names: individual
+      .name
+      .iter()
+      .map(|name| Name {
+        fact_type_id: NAME_FACT_TYPE_ID,
+        given_names: name.given_names().unwrap_or_default(),
+        surnames: name.surnames().unwrap_or_default(),
+      })
+      .collect(),
+    facts: individual
+      .events
+      .iter()
+      .filter_map(|event| {
+        Some(transform_fact(
+          fact_type_id(event.tag())?,
+          event.detail(),
+          None,
+        ))
+      })
+      .chain(individual.attributes.iter().filter_map(|attribute| {
+        let detail = attribute.detail();
+        Some(transform_fact(
+          fact_type_id(attribute.tag())?,
+          detail,
+          detail.value.as_deref(),
+        ))
+      }))
+      .collect(),
+  }
+}
+
+const NAME_FACT_TYPE_ID: u16 = 100;
+
+/// Every fact type that a name or fact is given, as the Gedcom tag it comes from, its id and its
+/// name. They are all written to `FactTypes`, so that every fact type id can be looked up.
+const FACT_TYPES: &[(&str, u16, &str)] = &[
+  ("NAME", NAME_FACT_TYPE_ID, "Name"),
+  ("BIRT", 405, "Birth"),
+  ("CAST", 410, "Caste"),
+  ("DSCR", 411, "Physical Description"),
+  ("EDUC", 412, "Education"),
+  ("IDNO", 413, "National ID Number"),
+  ("NATI", 414, "Nationality"),
+  ("NCHI", 415, "Number of Children"),
+  ("NMR", 416, "Number of Marriages"),
+  ("OCCU", 417, "Occupation"),
+  ("PROP", 418, "Possessions"),
+  ("RELI", 419, "Religion"),
+  ("RESI", 420, "Residence"),
+  ("SSN", 421, "Social Security Number"),
+  ("TITL", 422, "Title"),
+  ("FACT", 423, "Fact"),
+  ("CHR", 430, "Christening"),
+  ("DEAT", 431, "Death"),
+  ("BURI", 432, "Burial"),
+  ("CREM", 433, "Cremation"),
+  ("ADOP", 434, "Adoption"),
+  ("BAPM", 435, "Baptism"),
+  ("BARM", 436, "Bar Mitzvah"),
+  ("BASM", 437, "Bas Mitzvah"),
+  ("BLES", 438, "Blessing"),
+  ("CHRA", 439, "Adult Christening"),
+  ("CONF", 440, "Confirmation"),
+  ("FCOM", 441, "First Communion"),
+  ("ORDN", 442, "Ordination"),
+  ("NATU", 443, "Naturalization"),
+  ("EMIG", 444, "Emigration"),
+  ("IMMI", 445, "Immigration"),
+  ("CENS", 446, "Census"),
+  ("PROB", 447, "Probate"),
+  ("WILL", 448, "Will"),
+  ("GRAD", 449, "Graduation"),
+  ("RETI", 450, "Retirement"),
+  ("EVEN", 451, "Event"),
+];
+
+fn fact_type_id(tag: &str) -> Option<u16> {
+  FACT_TYPES
+    .iter()
+    .find(|(fact_type_tag, _, _)| *fact_type_tag == tag)
+    .map(|&(_, id, _)| id)
+}
+
+fn transform_fact<'input>(
+  fact_type_id: u16,
+  detail: &'input EventDetail,
+  value: Option<&'input str>,
+) -> Fact<'input> {
+  let map = detail.place.as_ref().and_then(|place| place.map);
+  Fact {
+    fact_type_id,
+    value,
+    date_detail: detail.date.as_ref().map(DateValue::to_string),
+    place: Place {
+      place_name: detail.place.as_ref().map(|place| place.name.as_ref()),
+      latitude: map.map(|map| map.latitude),
+      longitude: map.map(|map| map.longitude),
+    },
+    preferred: detail.is_primary,
+  }
+}
+
+fn transform_repository<'input>(
+  repository: &'input RepositoryRecord,
+  id: u32,
+) -> SourceRepo<'input> {
+  SourceRepo {
+    id,
+    name: repository.name,
+    address: repository.address.as_ref().map(format_address),
+    phone_number: repository.phone_numbers.first().copied(),
+    email: repository.email_addresses.first().copied(),
+    web_site: repository.web_pages.first().copied(),
   }
 }
+
+/// The `ADR1` to `ADR3` lines are often copies of lines of the whole address, so only those that
+/// are not are added to it.
+fn format_address(address: &Address) -> String {
+  let address_lines: Vec<&str> = address
+    .address_line
+    .lines()
+    .filter(|line| !line.is_empty())
+    .collect();
+  let separate_lines = [&address.line1, &address.line2, &address.line3]
+    .iter()
+    .filter_map(|line| line.as_deref())
+    .filter(|line| !address_lines.contains(line))
+    .collect::<Vec<_>>();
+  address_lines
+    .iter()
+    .copied()
+    .chain(separate_lines)
+    .chain(address.city.as_deref())
+    .chain(address.state.as_deref())
+    .chain(address.post_code.as_deref())
+    .chain(address.country.as_deref())
+    .collect::<Vec<_>>()
+    .join("\n")
+}
+
+/// Only the first repository citation of a source is kept, since a master source has one
+/// repository.
+fn transform_source<'input>(
+  source: &'input SourceRecord,
+  id: u32,
+  source_repo_ids: &HashMap<&str, u32>,
+) -> MasterSource<'input> {
+  let repository = source.repositories.first();
+  MasterSource {
+    id,
+    title: source.title.as_deref(),
+    author: source.author.as_deref(),
+    publication: source.publication.as_deref(),
+    text: source.text.as_deref(),
+    source_repo_id: repository
+      .and_then(|repository| repository.repository)
+      .and_then(|repository_id| source_repo_ids.get(repository_id).copied()),
+    call_number: repository
+      .and_then(|repository| repository.call_numbers.first())
+      .map(|call_number| call_number.call_number),
+  }
+}
+
+/// The multimedia linked to an individual, then to each of their events and attributes.
+fn individual_multimedia_links<'input>(
+  individual: &'input Individual,
+) -> impl Iterator<Item = &'input MultimediaLink<'input>> {
+  individual
+    .multimedia_links
+    .iter()
+    .chain(
+      individual
+        .events
+        .iter()
+        .flat_map(|event| &event.detail().multimedia_links),
+    )
+    .chain(
+      individual
+        .attributes
+        .iter()
+        .flat_map(|attribute| &attribute.detail().multimedia_links),
+    )
+}
+
+/// Returns `None` if the link has no files, and does not point to a multimedia record with files.
+fn transform_multimedia_link<'input>(
+  link: &'input MultimediaLink,
+  id: u32,
+  person_id: u32,
+  multimedia_records: &HashMap<&str, &'input MultimediaRecord>,
+) -> Option<Media<'input>> {
+  let files = if link.files.is_empty() {
+    &multimedia_records.get(link.object?)?.files
+  } else {
+    &link.files
+  };
+  let file = files.first()?;
+
+  Some(Media {
+    id,
+    person_id,
+    file_name: file.file,
+    title: link.title.as_deref().or(file.title.as_deref()),
+    format: file.format.as_ref().map(|format| format.format),
+    is_primary: link.is_primary,
+  })
+}
+
+fn transform_family(family: &GedcomFamily, id: u32, person_ids: &HashMap<&str, u32>) -> Family {
+  Family {
+    id,
+    father_id: family
+      .husband
+      .and_then(|husband| person_ids.get(husband).copied()),
+    mother_id: family.wife.and_then(|wife| person_ids.get(wife).copied()),
+  }
+}
+
+/// Returns `None` if the child pointer does not resolve to an individual.
+fn transform_child(
+  child_xref_id: &str,
+  family_xref_id: &str,
+  id: u32,
+  family_id: u32,
+  pedigrees: &HashMap<(&str, &str), Option<Pedigree>>,
+  person_ids: &HashMap<&str, u32>,
+) -> Option<Child> {
+  let person_id = *person_ids.get(child_xref_id)?;
+  let pedigree = pedigrees
+    .get(&(child_xref_id, family_xref_id))
+    .copied()
+    .flatten();
+
+  Some(Child {
+    id,
+    family_id,
+    person_id,
+    // Per the Gedcom spec, a missing PEDI means the child is related by birth.
+    relationship_type: match pedigree {
+      None | Some(Pedigree::Birth) => RelationshipType::Birth,
+      Some(Pedigree::Adopted) => RelationshipType::Adopted,
+      Some(Pedigree::Foster) => RelationshipType::Foster,
+      Some(Pedigree::Sealing) => RelationshipType::Sealing,
+    },
+  })
+}